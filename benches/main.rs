@@ -22,6 +22,7 @@ fn all_benches(runner: &mut Criterion) {
     bench_value(runner, "String_100b_1b_chars", STR_100B_1B_CHARS.to_string());
     bench_value(runner, "String_100b_4b_chars", STR_100B_4B_CHARS.to_string());
     bench_value(runner, "Vec_100b_u32", (0u32..25).collect::<Vec<u32>>());
+    bench_value(runner, "Vec_100b_u8", (0u8..100).collect::<Vec<u8>>());
     bench_value(
         runner,
         "struct, 200b",
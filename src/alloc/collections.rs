@@ -0,0 +1,229 @@
+//! Implementations of `ToFromBytes` for the `alloc::collections` types.
+//!
+//! Maps and sets use the same `u32` length prefix as `Vec<T>`, followed by their entries in
+//! ascending key order. Decoding requires strictly ascending, duplicate-free keys and returns
+//! `InvalidValue` otherwise, so every map and set has exactly one valid encoding.
+//!
+//! `VecDeque<T>`, `BinaryHeap<T>` and `LinkedList<T>` share the `Vec<T>` wire layout, including
+//! its limits and bounded preallocation. A `Vec<T>` on one side can decode as any of them on the other.
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::vec::Vec;
+
+use crate::{BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+impl<'a, K, V> ToFromBytes<'a> for BTreeMap<K, V>
+where
+    K: ToFromBytes<'a> + Ord,
+    V: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = 1_048_576; // 1 MiB
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+
+        for (key, value) in self {
+            writer.write(key)?;
+            writer.write(value)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let mut map = BTreeMap::new();
+
+        for _i in 0..len {
+            let key: K = reader.read()?;
+            let value: V = reader.read()?;
+
+            if map.last_key_value().is_some_and(|(last, _)| key <= *last) {
+                return Err(ToFromByteError::InvalidValue);
+            }
+
+            map.insert(key, value);
+        }
+
+        Ok((map, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for (key, value) in self.iter() {
+            byte_count += key.byte_count() + value.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+impl<'a, T> ToFromBytes<'a> for BTreeSet<T>
+where
+    T: ToFromBytes<'a> + Ord,
+{
+    const MAX_BYTES: usize = 1_048_576; // 1 MiB
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+
+        for item in self {
+            writer.write(item)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let mut set = BTreeSet::new();
+
+        for _i in 0..len {
+            let item: T = reader.read()?;
+
+            if set.last().is_some_and(|last| item <= *last) {
+                return Err(ToFromByteError::InvalidValue);
+            }
+
+            set.insert(item);
+        }
+
+        Ok((set, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for item in self.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+impl<'a, T> ToFromBytes<'a> for VecDeque<T>
+where
+    T: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = Vec::<T>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+
+        let (front, back) = self.as_slices();
+
+        T::write_slice(front, writer)?;
+        T::write_slice(back, writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let mut value = VecDeque::with_capacity(reader.capacity_for::<T>(len));
+
+        T::read_into(reader, len, &mut value)?;
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for item in self.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+/// Elements are written in the heap's internal order, not sorted order.
+///
+/// Decoding rebuilds the heap from whatever order arrives, so any permutation is accepted.
+impl<'a, T> ToFromBytes<'a> for BinaryHeap<T>
+where
+    T: ToFromBytes<'a> + Ord,
+{
+    const MAX_BYTES: usize = Vec::<T>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+
+        T::write_slice(self.as_slice(), writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = Vec::<T>::from_bytes(reader)?;
+
+        Ok((BinaryHeap::from(value), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for item in self.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+impl<'a, T> ToFromBytes<'a> for LinkedList<T>
+where
+    T: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = Vec::<T>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+
+        for item in self {
+            writer.write(item)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        // Nodes are allocated one at a time, so there is nothing to preallocate
+        let mut value = LinkedList::new();
+
+        T::read_into(reader, len, &mut value)?;
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for item in self.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
@@ -8,12 +8,14 @@
 //! All functions here allocate the exact number of bytes needed using `byte_count()`,
 //! so there's no overallocation or guessing.
 //!
-//! Implements the common alloc types String and Vec.
+//! Implements the common alloc types String and Vec, as well as smart pointers, `Cow` and the `alloc::collections` types.
 //!
 //! Also provides the to_bytes convenience function that abstracts away the buffer entirely.
 
 /// Contains alloc only API functions.
 pub mod api;
+pub mod collections;
 pub mod owned;
+pub mod pointers;
 
 pub use api::to_bytes;
@@ -1,14 +1,18 @@
-//! Implementations of `ToFromBytes` for owned collection types (`String`, `Vec<T>`).
+//! Implementations of `ToFromBytes` for owned collection types (`String`, `CString`, `Vec<T>`, `Box<[T]>`),
+//! and their `Prefixed<P, T>` variants.
 //!
 //! These live in the `alloc` crate because they require allocation during deserialization.
 //! The core crate remains completely `no-std` and zero-allocation.
 
 extern crate alloc;
 
+use alloc::boxed::Box;
+use alloc::ffi::CString;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::ffi::CStr;
 
-use crate::{BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+use crate::{BytesReader, BytesWriter, LengthPrefix, Prefixed, ToFromByteError, ToFromBytes};
 
 impl<'a, T> ToFromBytes<'a> for Vec<T>
 where
@@ -18,28 +22,53 @@ where
 
     #[inline(always)]
     fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
-        let len = u32::try_from(self.len()).map_err(|_| ToFromByteError::InvalidValue)?;
+        writer.write_len::<Self>(self.len())?;
 
-        writer.write(&len)?;
+        T::write_slice(self, writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let mut value = Vec::with_capacity(reader.capacity_for::<T>(len));
+
+        T::read_into(reader, len, &mut value)?;
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
 
-        for item in self {
-            writer.write(item)?;
+        for item in self.iter() {
+            byte_count += item.byte_count();
         }
 
-        Ok(())
+        byte_count
     }
+}
+
+/// Same wire layout as `Vec<T>`, so either side can use whichever type fits.
+impl<'a, T> ToFromBytes<'a> for Box<[T]>
+where
+    T: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = Vec::<T>::MAX_BYTES;
 
     #[inline(always)]
-    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
-        let len: u32 = reader.read()?;
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
 
-        let mut value = Vec::with_capacity(len as usize);
+        T::write_slice(self, writer)
+    }
 
-        for _i in 0..len {
-            value.push(reader.read()?);
-        }
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = Vec::<T>::from_bytes(reader)?;
 
-        Ok((value, reader.pos))
+        Ok((value.into_boxed_slice(), pos))
     }
 
     #[inline(always)]
@@ -59,9 +88,7 @@ impl<'a> ToFromBytes<'a> for String {
 
     #[inline(always)]
     fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
-        let len = u32::try_from(self.len()).map_err(|_| ToFromByteError::InvalidValue)?;
-
-        writer.write(&len)?;
+        writer.write_len::<Self>(self.len())?;
         writer.write_bytes(self.as_bytes())?;
 
         Ok(())
@@ -69,9 +96,9 @@ impl<'a> ToFromBytes<'a> for String {
 
     #[inline(always)]
     fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
-        let len: u32 = reader.read()?;
+        let len = reader.read_len::<Self>()?;
 
-        let bytes = reader.read_bytes(len as usize)?;
+        let bytes = reader.read_bytes(len)?;
 
         let value = String::from_utf8(bytes.to_vec()).map_err(|_| ToFromByteError::InvalidValue)?;
 
@@ -83,3 +110,93 @@ impl<'a> ToFromBytes<'a> for String {
         4 + self.len()
     }
 }
+
+/// Same NUL-terminated layout and limit as `&'a CStr`.
+impl<'a> ToFromBytes<'a> for CString {
+    const MAX_BYTES: usize = <&'a CStr>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_bytes(self.as_bytes_with_nul())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = <&'a CStr>::from_bytes(reader)?;
+
+        Ok((CString::from(value), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        self.as_bytes_with_nul().len()
+    }
+}
+
+impl<'a, P, T> ToFromBytes<'a> for Prefixed<P, Vec<T>>
+where
+    P: LengthPrefix + ToFromBytes<'a>,
+    T: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = Vec::<T>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len_with::<Self, P>(self.value.len())?;
+
+        T::write_slice(&self.value, writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len_with::<Self, P>()?;
+
+        let mut value = Vec::with_capacity(reader.capacity_for::<T>(len));
+
+        T::read_into(reader, len, &mut value)?;
+
+        Ok((Prefixed::new(value), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = Self::prefix_byte_count(self.value.len());
+
+        for item in self.value.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+impl<'a, P> ToFromBytes<'a> for Prefixed<P, String>
+where
+    P: LengthPrefix + ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = String::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len_with::<Self, P>(self.value.len())?;
+        writer.write_bytes(self.value.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len_with::<Self, P>()?;
+
+        let bytes = reader.read_bytes(len)?;
+
+        let value = String::from_utf8(bytes.to_vec()).map_err(|_| ToFromByteError::InvalidValue)?;
+
+        Ok((Prefixed::new(value), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        Self::prefix_byte_count(self.value.len()) + self.value.len()
+    }
+}
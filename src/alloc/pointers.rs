@@ -0,0 +1,222 @@
+//! Implementations of `ToFromBytes` for smart pointers and `Cow`.
+//!
+//! Pointers are transparent: a `Box<T>`, `Rc<T>` or `Arc<T>` has exactly the same wire
+//! format as the `T` inside it. This is what makes recursive types like expression trees work.
+//!
+//! `Cow<'a, str>` and `Cow<'a, [u8]>` always decode to `Cow::Borrowed`, pointing into the
+//! `BytesReader` buffer. Call `into_owned` when the value needs to outlive the buffer.
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+use crate::{BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+impl<'a, T> ToFromBytes<'a> for Box<T>
+where
+    T: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = T::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        T::to_bytes(self, writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = T::from_bytes(reader)?;
+
+        Ok((Box::new(value), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        T::byte_count(self)
+    }
+}
+
+impl<'a, T> ToFromBytes<'a> for Rc<T>
+where
+    T: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = T::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        T::to_bytes(self, writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = T::from_bytes(reader)?;
+
+        Ok((Rc::new(value), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        T::byte_count(self)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<'a, T> ToFromBytes<'a> for Arc<T>
+where
+    T: ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = T::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        T::to_bytes(self, writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = T::from_bytes(reader)?;
+
+        Ok((Arc::new(value), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        T::byte_count(self)
+    }
+}
+
+impl<'a> ToFromBytes<'a> for Box<str> {
+    const MAX_BYTES: usize = String::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+        writer.write_bytes(self.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = String::from_bytes(reader)?;
+
+        Ok((value.into_boxed_str(), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4 + self.len()
+    }
+}
+
+impl<'a> ToFromBytes<'a> for Rc<str> {
+    const MAX_BYTES: usize = String::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+        writer.write_bytes(self.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let bytes = reader.read_bytes(len)?;
+
+        let value = core::str::from_utf8(bytes).map_err(|_| ToFromByteError::InvalidValue)?;
+
+        Ok((Rc::from(value), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4 + self.len()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<'a> ToFromBytes<'a> for Arc<str> {
+    const MAX_BYTES: usize = String::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+        writer.write_bytes(self.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let bytes = reader.read_bytes(len)?;
+
+        let value = core::str::from_utf8(bytes).map_err(|_| ToFromByteError::InvalidValue)?;
+
+        Ok((Arc::from(value), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4 + self.len()
+    }
+}
+
+/// Same wire layout and limit as `&'a str`, decodes to `Cow::Borrowed`.
+impl<'a> ToFromBytes<'a> for Cow<'a, str> {
+    const MAX_BYTES: usize = <&'a str>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+        writer.write_bytes(self.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = <&'a str>::from_bytes(reader)?;
+
+        Ok((Cow::Borrowed(value), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4 + self.len()
+    }
+}
+
+/// Same wire layout and limit as `&'a [u8]`, decodes to `Cow::Borrowed`.
+impl<'a> ToFromBytes<'a> for Cow<'a, [u8]> {
+    const MAX_BYTES: usize = <&'a [u8]>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+        writer.write_bytes(self)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let (value, pos) = <&'a [u8]>::from_bytes(reader)?;
+
+        Ok((Cow::Borrowed(value), pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4 + self.len()
+    }
+}
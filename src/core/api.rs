@@ -75,3 +75,27 @@ pub fn write_bytes<'a, T: ToFromBytes<'a>>(value: &T, buffer: &'a mut [u8]) -> R
 
     Ok(writer.pos)
 }
+
+/// Returns how many padding bytes are needed to move from `pos` to the next multiple of `align`.
+///
+/// This is the amount `BytesWriter::align_to` writes and `BytesReader::align_to` skips.
+/// Use it to account for padding in `byte_count`, alignment is relative to the start of the buffer:
+///
+/// ```rust
+/// # use minbin::padding_for;
+/// let mut byte_count = 1; // u8 tag
+/// byte_count += padding_for(byte_count, 8);
+/// byte_count += 8; // u64 aligned to 8
+///
+/// assert_eq!(16, byte_count);
+/// ```
+///
+/// An `align` of 0 or 1 never needs padding.
+#[inline(always)]
+pub const fn padding_for(pos: usize, align: usize) -> usize {
+    if align <= 1 {
+        return 0;
+    }
+
+    (align - pos % align) % align
+}
@@ -0,0 +1,190 @@
+//! Bit-level writer and reader, layered on top of `BytesWriter` and `BytesReader`.
+//!
+//! For links where every bit counts: flags, 3-bit states and 12-bit readings packed
+//! back to back instead of each taking a whole byte.
+//!
+//! Values are written MSB-first, so the first field ends up in the high bits of the first byte.
+//! Complete bytes go straight to the underlying writer, so its `pos` is always synced
+//! up to the last full byte. `finish` pads the last partial byte with zero bits and
+//! leaves the byte writer or reader at the next byte boundary.
+
+use crate::{BytesReader, BytesWriter, ToFromByteError};
+
+/// Writes N-bit values into a `BytesWriter`.
+///
+/// Call `finish` when done, bits of a partial byte are lost if the writer is dropped.
+pub struct BitWriter<'w, 'a> {
+    writer: &'w mut BytesWriter<'a>,
+    byte: u8,
+    bits: u32,
+}
+
+impl<'w, 'a> BitWriter<'w, 'a> {
+    /// Start writing bits at the current position of `writer`.
+    #[inline(always)]
+    pub fn new(writer: &'w mut BytesWriter<'a>) -> Self {
+        Self { writer, byte: 0, bits: 0 }
+    }
+
+    /// Write the low `count` bits of `value`, MSB-first.
+    ///
+    /// Fails with `InvalidValue` if `count` is larger than 64 or `value` doesn't fit in `count` bits.
+    #[inline(always)]
+    pub fn write_bits(&mut self, value: u64, count: u32) -> Result<(), ToFromByteError> {
+        if count > 64 || value & !low_mask(count) != 0 {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        let mut count = count;
+
+        while count > 0 {
+            let take = count.min(8 - self.bits);
+            let chunk = (value >> (count - take)) & low_mask(take);
+
+            self.byte = ((u32::from(self.byte) << take) as u8) | chunk as u8;
+            self.bits += take;
+            count -= take;
+
+            if self.bits == 8 {
+                self.writer.write_bytes(&[self.byte])?;
+
+                self.byte = 0;
+                self.bits = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write `value` as a `count`-bit two's complement number, MSB-first.
+    ///
+    /// Fails with `InvalidValue` if `count` is larger than 64 or `value` doesn't fit in `count` bits.
+    #[inline(always)]
+    pub fn write_signed(&mut self, value: i64, count: u32) -> Result<(), ToFromByteError> {
+        if count == 0 {
+            return self.write_bits(value as u64, 0);
+        }
+
+        if count > 64 {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        let shift = 64 - count;
+
+        if (value << shift) >> shift != value {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        self.write_bits(value as u64 & low_mask(count), count)
+    }
+
+    /// Write a single bit.
+    #[inline(always)]
+    pub fn write_bool(&mut self, value: bool) -> Result<(), ToFromByteError> {
+        self.write_bits(u64::from(value), 1)
+    }
+
+    /// Pad the partial byte, if any, with zero bits and write it.
+    ///
+    /// Afterwards the `BytesWriter` continues at the next byte boundary.
+    #[inline(always)]
+    pub fn finish(self) -> Result<(), ToFromByteError> {
+        if self.bits == 0 {
+            return Ok(());
+        }
+
+        self.writer.write_bytes(&[self.byte << (8 - self.bits)])
+    }
+}
+
+/// Reads N-bit values from a `BytesReader`.
+///
+/// Bytes are pulled from the reader as soon as their first bit is needed.
+pub struct BitReader<'r, 'a> {
+    reader: &'r mut BytesReader<'a>,
+    byte: u8,
+    bits: u32,
+}
+
+impl<'r, 'a> BitReader<'r, 'a> {
+    /// Start reading bits at the current position of `reader`.
+    #[inline(always)]
+    pub fn new(reader: &'r mut BytesReader<'a>) -> Self {
+        Self { reader, byte: 0, bits: 0 }
+    }
+
+    /// Read a `count`-bit unsigned value, MSB-first.
+    ///
+    /// Fails with `InvalidValue` if `count` is larger than 64.
+    #[inline(always)]
+    pub fn read_bits(&mut self, count: u32) -> Result<u64, ToFromByteError> {
+        if count > 64 {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        let mut count = count;
+        let mut value = 0u64;
+
+        while count > 0 {
+            if self.bits == 0 {
+                self.byte = self.reader.read::<u8>()?;
+                self.bits = 8;
+            }
+
+            let take = count.min(self.bits);
+            let chunk = u64::from(self.byte >> (self.bits - take)) & low_mask(take);
+
+            value = (value << take) | chunk;
+            self.bits -= take;
+            count -= take;
+        }
+
+        Ok(value)
+    }
+
+    /// Read a `count`-bit two's complement value, MSB-first, sign-extended to `i64`.
+    ///
+    /// Fails with `InvalidValue` if `count` is larger than 64.
+    #[inline(always)]
+    pub fn read_signed(&mut self, count: u32) -> Result<i64, ToFromByteError> {
+        if count == 0 {
+            return Ok(0);
+        }
+
+        if count > 64 {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        let shift = 64 - count;
+
+        Ok(((self.read_bits(count)? << shift) as i64) >> shift)
+    }
+
+    /// Read a single bit.
+    #[inline(always)]
+    pub fn read_bool(&mut self) -> Result<bool, ToFromByteError> {
+        Ok(self.read_bits(1)? == 1)
+    }
+
+    /// Discard the rest of the partial byte, if any.
+    ///
+    /// Afterwards the `BytesReader` continues at the next byte boundary.
+    /// With `config.strict` the discarded bits must be zero, otherwise this fails with `InvalidValue`.
+    #[inline(always)]
+    pub fn finish(self) -> Result<(), ToFromByteError> {
+        if self.reader.config.strict && self.byte & low_mask(self.bits) as u8 != 0 {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        Ok(())
+    }
+}
+
+#[inline(always)]
+const fn low_mask(count: u32) -> u64 {
+    if count >= 64 {
+        return u64::MAX;
+    }
+
+    (1u64 << count) - 1
+}
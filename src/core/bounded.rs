@@ -0,0 +1,308 @@
+//! Fixed-capacity containers that work without `alloc`.
+//!
+//! Without an allocator the only variable-length types are borrowed ones like `&'a str`,
+//! which keep the receive buffer alive for as long as the decoded value lives.
+//! These types copy their contents inline instead, so the buffer can be dropped right after decoding.
+//!
+//! The wire format is the same `u32` length prefix used by `Vec<T>` and `String`,
+//! so a bounded type on one side can talk to an owned type on the other.
+//! Payloads longer than the capacity are rejected with `MaxBytesExceeded`.
+//!
+//! Everything is safe Rust, so `BoundedVec` keeps every slot initialized and needs `T: Default`.
+
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+/// A `Vec<T>`-like container holding up to `N` elements inline.
+#[derive(Clone)]
+pub struct BoundedVec<T, const N: usize> {
+    items: [T; N],
+    len: usize,
+}
+
+impl<T: Default, const N: usize> BoundedVec<T, N> {
+    /// Creates an empty container.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self { items: core::array::from_fn(|_| T::default()), len: 0 }
+    }
+
+    /// Appends a value, failing with `MaxBytesExceeded` when the container is full.
+    #[inline(always)]
+    pub fn push(&mut self, value: T) -> Result<(), ToFromByteError> {
+        if self.len == N {
+            return Err(ToFromByteError::MaxBytesExceeded);
+        }
+
+        self.items[self.len] = value;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes and returns the last value, if any.
+    #[inline(always)]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        Some(core::mem::take(&mut self.items[self.len]))
+    }
+
+    /// Removes all values.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T, const N: usize> BoundedVec<T, N> {
+    /// The maximum number of elements this container can hold.
+    pub const CAPACITY: usize = N;
+
+    /// Returns the stored elements as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+
+    /// Returns the stored elements as a mutable slice.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.items[..self.len]
+    }
+}
+
+impl<T: Default, const N: usize> Default for BoundedVec<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for BoundedVec<T, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(formatter)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for BoundedVec<T, N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for BoundedVec<T, N> {}
+
+impl<T: Clone + Default, const N: usize> TryFrom<&[T]> for BoundedVec<T, N> {
+    type Error = ToFromByteError;
+
+    #[inline(always)]
+    fn try_from(values: &[T]) -> Result<Self, ToFromByteError> {
+        let mut bounded = Self::new();
+
+        for value in values {
+            bounded.push(value.clone())?;
+        }
+
+        Ok(bounded)
+    }
+}
+
+impl<'a, T, const N: usize> ToFromBytes<'a> for BoundedVec<T, N>
+where
+    T: ToFromBytes<'a> + Default,
+{
+    const MAX_BYTES: usize = N.saturating_mul(T::MAX_BYTES).saturating_add(4);
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len)?;
+
+        T::write_slice(self.as_slice(), writer)
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        if len > N {
+            return Err(ToFromByteError::MaxBytesExceeded);
+        }
+
+        let mut value = Self::new();
+
+        for _i in 0..len {
+            value.push(reader.read()?)?;
+        }
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for item in self.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+/// A `String`-like container holding up to `N` bytes of UTF-8 inline.
+#[derive(Clone, Copy)]
+pub struct BoundedString<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> BoundedString<N> {
+    /// The maximum number of bytes this string can hold.
+    pub const CAPACITY: usize = N;
+
+    /// Creates an empty string.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { bytes: [0; N], len: 0 }
+    }
+
+    /// Appends a string slice, failing with `MaxBytesExceeded` when it doesn't fit.
+    ///
+    /// Nothing is appended on failure.
+    #[inline(always)]
+    pub fn push_str(&mut self, value: &str) -> Result<(), ToFromByteError> {
+        let end = self.len + value.len();
+
+        if end > N {
+            return Err(ToFromByteError::MaxBytesExceeded);
+        }
+
+        self.bytes[self.len..end].copy_from_slice(value.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+
+    /// Appends a character, failing with `MaxBytesExceeded` when it doesn't fit.
+    #[inline(always)]
+    pub fn push(&mut self, value: char) -> Result<(), ToFromByteError> {
+        self.push_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Removes all characters.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the stored characters as a string slice.
+    ///
+    /// The bytes are only ever written from valid UTF-8, but without `unsafe` we cannot skip
+    /// the check, so this costs a validation pass over the contents.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.bytes[..self.len]) {
+            Ok(value) => value,
+            Err(_) => unreachable!("only built from valid UTF-8"),
+        }
+    }
+}
+
+impl<const N: usize> Default for BoundedString<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for BoundedString<N> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for BoundedString<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+impl<const N: usize> fmt::Display for BoundedString<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq for BoundedString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes[..self.len] == other.bytes[..other.len]
+    }
+}
+
+impl<const N: usize> Eq for BoundedString<N> {}
+
+impl<const N: usize> TryFrom<&str> for BoundedString<N> {
+    type Error = ToFromByteError;
+
+    #[inline(always)]
+    fn try_from(value: &str) -> Result<Self, ToFromByteError> {
+        let mut bounded = Self::new();
+
+        bounded.push_str(value)?;
+
+        Ok(bounded)
+    }
+}
+
+impl<const N: usize> ToFromBytes<'_> for BoundedString<N> {
+    const MAX_BYTES: usize = N.saturating_add(4);
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len)?;
+        writer.write_bytes(&self.bytes[..self.len])?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        if len > N {
+            return Err(ToFromByteError::MaxBytesExceeded);
+        }
+
+        let bytes = reader.read_bytes(len)?;
+
+        let value = core::str::from_utf8(bytes).map_err(|_| ToFromByteError::InvalidValue)?;
+
+        Ok((Self::try_from(value)?, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4 + self.len
+    }
+}
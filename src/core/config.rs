@@ -0,0 +1,43 @@
+//! Runtime configuration carried by `BytesReader` and `BytesWriter`.
+//!
+//! Everything else about the encoding is fixed at compile time through the types you pick.
+//! Some things can't be: the same message types are often used on a trusted internal link
+//! and on an untrusted public endpoint, and those want different limits and strictness.
+//!
+//! The config is a plain public field on the reader and writer, so manual `ToFromBytes`
+//! implementations can read it inside `to_bytes`/`from_bytes` and adapt, e.g. to the protocol version.
+//!
+//! `BytesReader::new` and `BytesWriter::new` use `Config::DEFAULT`, which matches the behaviour
+//! of minbin without any configuration.
+
+/// Limits, strictness and protocol version for one reader or writer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Config {
+    /// Upper bound on every length prefix read through `BytesReader::read_len` or written through
+    /// `BytesWriter::write_len`, on top of the type's `MAX_BYTES`.
+    ///
+    /// Lower it on untrusted endpoints to reject large collections early. Set the same limit on the
+    /// writing side to fail before sending what the peer would reject. Default: no extra limit.
+    pub max_len: usize,
+    /// Opt-in stricter validation where the format allows more than one encoding.
+    ///
+    /// Built-in types are already strict about values, this is for checks that cost extra work
+    /// or would reject data older peers produce, like non-zero padding bytes. Default: `false`.
+    pub strict: bool,
+    /// Protocol version, for manual implementations that need to read or write older layouts.
+    ///
+    /// minbin itself never looks at it. Default: `0`.
+    pub version: u32,
+}
+
+impl Config {
+    /// The configuration used by `BytesReader::new` and `BytesWriter::new`.
+    pub const DEFAULT: Config = Config { max_len: usize::MAX, strict: false, version: 0 };
+}
+
+impl Default for Config {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
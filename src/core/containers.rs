@@ -1,3 +1,7 @@
+use core::ffi::CStr;
+use core::ops::{Bound, Range, RangeInclusive};
+use core::time::Duration;
+
 use crate::{to_from_bytes_tuple, BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
 
 impl<'a, T: ToFromBytes<'a>> ToFromBytes<'a> for Option<T> {
@@ -44,14 +48,242 @@ impl<'a, T: ToFromBytes<'a>> ToFromBytes<'a> for Option<T> {
     }
 }
 
+/// Mirrors the `Option<T>` layout: a tag byte (`0` for `Ok`, `1` for `Err`) followed by the payload.
+impl<'a, T: ToFromBytes<'a>, E: ToFromBytes<'a>> ToFromBytes<'a> for Result<T, E> {
+    const MAX_BYTES: usize = 1 + if T::MAX_BYTES > E::MAX_BYTES { T::MAX_BYTES } else { E::MAX_BYTES };
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        match self {
+            Result::Ok(value) => {
+                writer.write(&0u8)?;
+                writer.write(value)?;
+
+                Ok(())
+            }
+            Result::Err(error) => {
+                writer.write(&1u8)?;
+                writer.write(error)?;
+
+                Ok(())
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let result_byte: u8 = reader.read()?;
+
+        match result_byte {
+            0 => {
+                let value = reader.read()?;
+
+                Ok((Ok(value), reader.pos))
+            }
+            1 => {
+                let error = reader.read()?;
+
+                Ok((Err(error), reader.pos))
+            }
+            _ => Err(ToFromByteError::InvalidValue),
+        }
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        match self.as_ref() {
+            Ok(value) => 1 + value.byte_count(),
+            Err(error) => 1 + error.byte_count(),
+        }
+    }
+}
+
+/// Serialized as `u64` seconds followed by `u32` nanoseconds (12 bytes).
+///
+/// Decoding rejects nanoseconds of one second or more with `InvalidValue`,
+/// so every duration has exactly one valid encoding.
+impl ToFromBytes<'_> for Duration {
+    const MAX_BYTES: usize = 12;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write(&self.as_secs())?;
+        writer.write(&self.subsec_nanos())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let secs: u64 = reader.read()?;
+        let nanos: u32 = reader.read()?;
+
+        if nanos >= 1_000_000_000 {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        Ok((Duration::new(secs, nanos), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        12
+    }
+}
+
+/// Serialized as `start` followed by `end`, same as the tuple `(start, end)`.
+///
+/// Empty and reversed ranges are valid Rust values, so they are not rejected.
+impl<'a, T: ToFromBytes<'a>> ToFromBytes<'a> for Range<T> {
+    const MAX_BYTES: usize = T::MAX_BYTES.saturating_mul(2);
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write(&self.start)?;
+        writer.write(&self.end)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let start = reader.read()?;
+        let end = reader.read()?;
+
+        Ok((start..end, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        self.start.byte_count() + self.end.byte_count()
+    }
+}
+
+/// Serialized as `start` followed by `end`, same layout as `Range<T>`.
+///
+/// Only the bounds are sent, a range that was exhausted by iteration decodes as a fresh one.
+impl<'a, T: ToFromBytes<'a>> ToFromBytes<'a> for RangeInclusive<T> {
+    const MAX_BYTES: usize = T::MAX_BYTES.saturating_mul(2);
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write(self.start())?;
+        writer.write(self.end())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let start = reader.read()?;
+        let end = reader.read()?;
+
+        Ok((start..=end, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        self.start().byte_count() + self.end().byte_count()
+    }
+}
+
+/// Serialized as a tag byte (`0` included, `1` excluded, `2` unbounded) followed by the value, if any.
+impl<'a, T: ToFromBytes<'a>> ToFromBytes<'a> for Bound<T> {
+    const MAX_BYTES: usize = 1 + T::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        match self {
+            Bound::Included(value) => {
+                writer.write(&0u8)?;
+                writer.write(value)?;
+            }
+            Bound::Excluded(value) => {
+                writer.write(&1u8)?;
+                writer.write(value)?;
+            }
+            Bound::Unbounded => {
+                writer.write(&2u8)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let bound_byte: u8 = reader.read()?;
+
+        match bound_byte {
+            0 => Ok((Bound::Included(reader.read()?), reader.pos)),
+            1 => Ok((Bound::Excluded(reader.read()?), reader.pos)),
+            2 => Ok((Bound::Unbounded, reader.pos)),
+            _ => Err(ToFromByteError::InvalidValue),
+        }
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        match self {
+            Bound::Included(value) | Bound::Excluded(value) => 1 + value.byte_count(),
+            Bound::Unbounded => 1,
+        }
+    }
+}
+
+/// Fixed-size arrays are written as `N` elements back to back, without a length prefix.
+///
+/// The length is part of the type, so both sides already agree on it.
+impl<'a, T: ToFromBytes<'a>, const N: usize> ToFromBytes<'a> for [T; N] {
+    const MAX_BYTES: usize = N.saturating_mul(T::MAX_BYTES);
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        for item in self {
+            writer.write(item)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let mut error = None;
+
+        // `from_fn` calls the closure in index order, so elements are read in wire order.
+        // After the first error we stop reading and only fill the remaining slots.
+        let values: [Result<T, ToFromByteError>; N] = core::array::from_fn(|_| match error {
+            Some(error) => Err(error),
+            None => reader.read().inspect_err(|read_error| error = Some(*read_error)),
+        });
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        let value = values.map(|value| value.unwrap_or_else(|_| unreachable!("errors are returned above")));
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 0;
+
+        for item in self.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
+
 impl<'a> ToFromBytes<'a> for &'a str {
     const MAX_BYTES: usize = 102_400; // 100 KiB
 
     #[inline(always)]
     fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
-        let len = u32::try_from(self.len()).map_err(|_| ToFromByteError::InvalidValue)?;
-
-        writer.write(&len)?;
+        writer.write_len::<Self>(self.len())?;
         writer.write_bytes(self.as_bytes())?;
 
         Ok(())
@@ -59,9 +291,9 @@ impl<'a> ToFromBytes<'a> for &'a str {
 
     #[inline(always)]
     fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
-        let len: u32 = reader.read()?;
+        let len = reader.read_len::<Self>()?;
 
-        let bytes = reader.read_bytes(len as usize)?;
+        let bytes = reader.read_bytes(len)?;
 
         let value = core::str::from_utf8(bytes).map_err(|_| ToFromByteError::InvalidValue)?;
 
@@ -74,6 +306,67 @@ impl<'a> ToFromBytes<'a> for &'a str {
     }
 }
 
+/// Raw bytes with a `u32` length prefix, borrowed from the reader buffer like `&'a str`.
+///
+/// No validation pass is needed, so this uses the same 1 MiB limit as `Vec<T>` instead of the string limit.
+impl<'a> ToFromBytes<'a> for &'a [u8] {
+    const MAX_BYTES: usize = 1_048_576; // 1 MiB
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+        writer.write_bytes(self)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let value = reader.read_bytes(len)?;
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4 + self.len()
+    }
+}
+
+/// NUL-terminated C string, for firmware that speaks C. The bytes are written followed by the NUL,
+/// there is no length prefix. Decoding is zero-copy, borrowing from the reader buffer like `&'a str`.
+///
+/// Fails with `NotEnoughBytes` when no terminator appears within the remaining buffer or `MAX_BYTES`.
+impl<'a> ToFromBytes<'a> for &'a CStr {
+    const MAX_BYTES: usize = 1_048_576; // 1 MiB
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write_bytes(self.to_bytes_with_nul())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let remaining = &reader.data[reader.pos..];
+        let window = &remaining[..remaining.len().min(Self::MAX_BYTES)];
+
+        let nul = window.iter().position(|byte| *byte == 0).ok_or(ToFromByteError::NotEnoughBytes)?;
+
+        let bytes = reader.read_bytes(nul + 1)?;
+
+        let value = CStr::from_bytes_with_nul(bytes).map_err(|_| ToFromByteError::InvalidValue)?;
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        self.to_bytes_with_nul().len()
+    }
+}
+
 to_from_bytes_tuple!();
 to_from_bytes_tuple!(T0);
 to_from_bytes_tuple!(T0, T1);
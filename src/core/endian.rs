@@ -0,0 +1,65 @@
+//! Byte order wrappers for talking to little-endian firmware and file formats.
+//!
+//! minbin is big-endian everywhere and that doesn't change: a bare `u32` is always big-endian.
+//! Wrap a field in `Le<T>` to write it little-endian instead, or in `Be<T>` to make the
+//! default byte order explicit next to `Le` fields.
+//!
+//! Both wrappers cover every fixed-width integer, `usize`/`isize` (always 8 bytes), the odd-width
+//! `U24`/`I24`/`U48`/`I48`, the `NonZero*` integers and the floats.
+//!
+//! ```rust
+//! use minbin::{read_bytes, write_bytes, Le};
+//!
+//! let mut buffer = [0u8; 4];
+//! write_bytes(&Le(0x0102_0304u32), &mut buffer).unwrap();
+//!
+//! assert_eq!([4, 3, 2, 1], buffer);
+//! assert_eq!(Le(0x0102_0304u32), read_bytes(&buffer).unwrap().0);
+//! ```
+
+use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+
+use crate::{to_from_bytes_endian, BytesReader, BytesWriter, ToFromByteError, ToFromBytes, I24, I48, U24, U48};
+
+/// Writes the wrapped number in little-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Le<T>(pub T);
+
+/// Writes the wrapped number in big-endian byte order, same as the bare type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Be<T>(pub T);
+
+to_from_bytes_endian!(u8, 1);
+to_from_bytes_endian!(u16, 2);
+to_from_bytes_endian!(u32, 4);
+to_from_bytes_endian!(u64, 8);
+to_from_bytes_endian!(u128, 16);
+
+to_from_bytes_endian!(i8, 1);
+to_from_bytes_endian!(i16, 2);
+to_from_bytes_endian!(i32, 4);
+to_from_bytes_endian!(i64, 8);
+to_from_bytes_endian!(i128, 16);
+
+to_from_bytes_endian!(usize as u64);
+to_from_bytes_endian!(isize as i64);
+
+to_from_bytes_endian!(f32, 4);
+to_from_bytes_endian!(f64, 8);
+
+to_from_bytes_endian!(odd U24, u32, 3);
+to_from_bytes_endian!(odd I24, i32, 3);
+to_from_bytes_endian!(odd U48, u64, 6);
+to_from_bytes_endian!(odd I48, i64, 6);
+
+to_from_bytes_endian!(nonzero NonZeroU8, u8);
+to_from_bytes_endian!(nonzero NonZeroU16, u16);
+to_from_bytes_endian!(nonzero NonZeroU32, u32);
+to_from_bytes_endian!(nonzero NonZeroU64, u64);
+to_from_bytes_endian!(nonzero NonZeroU128, u128);
+
+to_from_bytes_endian!(nonzero NonZeroI8, i8);
+to_from_bytes_endian!(nonzero NonZeroI16, i16);
+to_from_bytes_endian!(nonzero NonZeroI32, i32);
+to_from_bytes_endian!(nonzero NonZeroI64, i64);
+to_from_bytes_endian!(nonzero NonZeroI128, i128);
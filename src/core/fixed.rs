@@ -0,0 +1,28 @@
+//! Decimal fixed-point numbers for money amounts and calibrated readings.
+//!
+//! `Fixed<I, SCALE>` stores a signed integer mantissa and means `mantissa / 10^SCALE`,
+//! so `Fixed<i64, 2>` holds cents and displays as `12.34`. It is written exactly like the bare
+//! mantissa integer, so both sides only have to agree on `SCALE`.
+//!
+//! The rules are fixed in one place instead of in every team's code:
+//! - Conversions are checked and return `None` instead of overflowing.
+//! - Floats round half away from zero, integers truncate toward zero.
+//! - `I::MIN` is not a valid mantissa, keeping the range symmetric. Decoding it returns `InvalidValue`.
+//!
+//! Implemented for `i16`, `i32`, `i64` and `i128`. `Decimal<SCALE>` is shorthand for the common `i64` case.
+
+use core::fmt;
+
+use crate::{to_from_bytes_fixed, BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+/// A decimal fixed-point number, `mantissa / 10^SCALE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<I, const SCALE: u32>(I);
+
+/// A decimal fixed-point number with an `i64` mantissa, e.g. `Decimal<2>` for cents.
+pub type Decimal<const SCALE: u32> = Fixed<i64, SCALE>;
+
+to_from_bytes_fixed!(i16, u16);
+to_from_bytes_fixed!(i32, u32);
+to_from_bytes_fixed!(i64, u64);
+to_from_bytes_fixed!(i128, u128);
@@ -0,0 +1,27 @@
+//! Opt-in policies for `f32` and `f64`.
+//!
+//! The bare float implementations are bit-exact: whatever `to_bits` returns goes on the wire.
+//! That means two peers can encode the same logical value differently, e.g. NaNs with different
+//! payloads, or `-0.0` and `0.0` which compare equal.
+//!
+//! Wrap a field in one of these types when you need exactly one valid encoding per value.
+//! The layout is identical to the bare float, so switching only changes which values are accepted.
+
+use crate::{to_from_bytes_float_policy, BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+/// A float with exactly one encoding per logical value.
+///
+/// - Encoding collapses every NaN payload to `NAN` and `-0.0` to `0.0`.
+/// - Decoding rejects any other NaN payload and `-0.0` with `InvalidValue`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Canonical<T>(pub T);
+
+/// A canonical float that is also guaranteed to be finite.
+///
+/// Same rules as `Canonical`, but NaN and ±infinity are rejected with `InvalidValue`
+/// on both encode and decode.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Finite<T>(pub T);
+
+to_from_bytes_float_policy!(f32, 4);
+to_from_bytes_float_policy!(f64, 8);
@@ -7,10 +7,12 @@
 /// than hundreds of lines of boilerplate.
 ///
 /// - Every integer is serialized in big-endian (human friendly)
-/// - We only support fixed size integers, no var-ints (predictable size)
+/// - Bare integers are always fixed size (predictable size), var-ints are opt-in through the `Var*` wrappers
+///
+/// Extra trait items can be appended after the byte count, `u8` uses this for its bulk slice methods.
 #[macro_export]
 macro_rules! to_from_bytes_int {
-    ($int: ty, $byte_count: literal) => {
+    ($int: ty, $byte_count: literal $(, $extra: item)*) => {
         impl ToFromBytes<'_> for $int {
             const MAX_BYTES: usize = $byte_count;
 
@@ -31,6 +33,545 @@ macro_rules! to_from_bytes_int {
             fn byte_count(&self) -> usize {
                 $byte_count
             }
+
+            $($extra)*
+        }
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for the odd-width integer newtypes (U24, I24, U48, I48).
+///
+/// The value is stored in the next native integer up and written as its lowest `$byte_count`
+/// big-endian bytes. Shifting the value up and back down by the unused bits is a no-op exactly when
+/// it fits, and for signed integers the arithmetic shift right also sign-extends on decode.
+#[macro_export]
+macro_rules! to_from_bytes_odd_int {
+    ($name: ident, $int: ty, $byte_count: literal) => {
+        impl $name {
+            const UNUSED_BITS: u32 = <$int>::BITS - $byte_count * 8;
+
+            /// The smallest value that fits on the wire.
+            pub const MIN: $int = <$int>::MIN >> Self::UNUSED_BITS;
+            /// The largest value that fits on the wire.
+            pub const MAX: $int = <$int>::MAX >> Self::UNUSED_BITS;
+        }
+
+        impl ToFromBytes<'_> for $name {
+            const MAX_BYTES: usize = $byte_count;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                if (self.0 << Self::UNUSED_BITS) >> Self::UNUSED_BITS != self.0 {
+                    return Err(ToFromByteError::InvalidValue);
+                }
+
+                let bytes = self.0.to_be_bytes();
+
+                writer.write_bytes(&bytes[bytes.len() - $byte_count..])
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let mut bytes = [0u8; <$int>::BITS as usize / 8];
+                let len = bytes.len();
+
+                bytes[len - $byte_count..].copy_from_slice(reader.read_bytes($byte_count)?);
+
+                let value = (<$int>::from_be_bytes(bytes) << Self::UNUSED_BITS) >> Self::UNUSED_BITS;
+
+                Ok(($name(value), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                $byte_count
+            }
+        }
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for the pointer-sized integers (usize, isize).
+///
+/// - Always 8 bytes on the wire, no matter the platform, so 32 and 64-bit peers stay compatible
+/// - Values that don't fit the native width are rejected with `InvalidValue`, never truncated
+#[macro_export]
+macro_rules! to_from_bytes_size {
+    ($size: ty, $int: ty) => {
+        impl ToFromBytes<'_> for $size {
+            const MAX_BYTES: usize = <$int>::MAX_BYTES;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                let value = <$int>::try_from(*self).map_err(|_| ToFromByteError::InvalidValue)?;
+
+                writer.write(&value)
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let value: $int = reader.read()?;
+                let value = <$size>::try_from(value).map_err(|_| ToFromByteError::InvalidValue)?;
+
+                Ok((value, reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                <$int>::MAX_BYTES
+            }
+        }
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for the `Le<T>` and `Be<T>` byte order wrappers.
+///
+/// - `$num, $byte_count` covers the fixed-width integers and floats, which all have `to_le_bytes`/`from_le_bytes`
+/// - `$size as $int` covers usize/isize, which go through `$int` to stay 8 bytes on every platform
+/// - `odd $name, $int, $byte_count` covers the odd-width integers, range checked and sign-extended like the bare types
+/// - `nonzero $nonzero, $int` covers the NonZero integers, rejecting zero like the bare types
+/// - `Be<T>` of the last three is the bare type's encoding, shared through `@be`
+#[macro_export]
+macro_rules! to_from_bytes_endian {
+    (odd $name: ident, $int: ty, $byte_count: literal) => {
+        impl ToFromBytes<'_> for Le<$name> {
+            const MAX_BYTES: usize = $byte_count;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                const UNUSED_BITS: u32 = <$int>::BITS - $byte_count * 8;
+
+                let value = (self.0).0;
+
+                if (value << UNUSED_BITS) >> UNUSED_BITS != value {
+                    return Err(ToFromByteError::InvalidValue);
+                }
+
+                writer.write_bytes(&value.to_le_bytes()[..$byte_count])
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                const UNUSED_BITS: u32 = <$int>::BITS - $byte_count * 8;
+
+                let mut bytes = [0u8; <$int>::BITS as usize / 8];
+
+                bytes[..$byte_count].copy_from_slice(reader.read_bytes($byte_count)?);
+
+                let value = (<$int>::from_le_bytes(bytes) << UNUSED_BITS) >> UNUSED_BITS;
+
+                Ok((Le($name(value)), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                $byte_count
+            }
+        }
+
+        to_from_bytes_endian!(@be $name);
+    };
+
+    (nonzero $nonzero: ty, $int: ty) => {
+        impl ToFromBytes<'_> for Le<$nonzero> {
+            const MAX_BYTES: usize = <$int>::MAX_BYTES;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write(&Le(self.0.get()))
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let Le(value): Le<$int> = reader.read()?;
+                let value = <$nonzero>::new(value).ok_or(ToFromByteError::InvalidValue)?;
+
+                Ok((Le(value), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                <$int>::MAX_BYTES
+            }
+        }
+
+        to_from_bytes_endian!(@be $nonzero);
+    };
+
+    (@be $ty: ty) => {
+        impl ToFromBytes<'_> for Be<$ty> {
+            const MAX_BYTES: usize = <$ty>::MAX_BYTES;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write(&self.0)
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let value = reader.read()?;
+
+                Ok((Be(value), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                <$ty>::MAX_BYTES
+            }
+        }
+    };
+
+    ($num: ty, $byte_count: literal) => {
+        impl ToFromBytes<'_> for Le<$num> {
+            const MAX_BYTES: usize = $byte_count;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write_bytes(&self.0.to_le_bytes())
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let bytes = reader.read_bytes($byte_count)?;
+                let bytes = bytes.try_into().map_err(|_| ToFromByteError::NotEnoughBytes)?;
+
+                Ok((Le(<$num>::from_le_bytes(bytes)), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                $byte_count
+            }
+        }
+
+        impl ToFromBytes<'_> for Be<$num> {
+            const MAX_BYTES: usize = $byte_count;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write_bytes(&self.0.to_be_bytes())
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let bytes = reader.read_bytes($byte_count)?;
+                let bytes = bytes.try_into().map_err(|_| ToFromByteError::NotEnoughBytes)?;
+
+                Ok((Be(<$num>::from_be_bytes(bytes)), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                $byte_count
+            }
+        }
+    };
+
+    ($size: ty as $int: ty) => {
+        impl ToFromBytes<'_> for Le<$size> {
+            const MAX_BYTES: usize = <$int>::MAX_BYTES;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                let value = <$int>::try_from(self.0).map_err(|_| ToFromByteError::InvalidValue)?;
+
+                writer.write(&Le(value))
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let Le(value): Le<$int> = reader.read()?;
+                let value = <$size>::try_from(value).map_err(|_| ToFromByteError::InvalidValue)?;
+
+                Ok((Le(value), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                <$int>::MAX_BYTES
+            }
+        }
+
+        to_from_bytes_endian!(@be $size);
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for the unsigned LEB128 var-int wrappers.
+///
+/// - 7 bits per byte, least significant group first, high bit set on every byte but the last
+/// - `$max_bytes` is `ceil(BITS / 7)`, longer encodings are rejected with `InvalidValue`
+/// - Overlong encodings (a zero last byte after the first) and bits past the integer width are rejected too,
+///   so every value has exactly one valid encoding
+#[macro_export]
+macro_rules! to_from_bytes_varint {
+    ($name: ident, $int: ty, $max_bytes: literal) => {
+        impl ToFromBytes<'_> for $name {
+            const MAX_BYTES: usize = $max_bytes;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                let mut bytes = [0u8; $max_bytes];
+                let mut value = self.0;
+                let mut len = 0;
+
+                loop {
+                    bytes[len] = (value & 0x7f) as u8;
+                    value >>= 7;
+                    len += 1;
+
+                    if value == 0 {
+                        break;
+                    }
+
+                    bytes[len - 1] |= 0x80;
+                }
+
+                writer.write_bytes(&bytes[..len])
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let mut value: $int = 0;
+
+                for index in 0..$max_bytes {
+                    let byte = reader.read_bytes(1)?[0];
+                    let group = (byte & 0x7f) as $int;
+                    let shift = 7 * index;
+
+                    // Bits shifted past the integer width would be silently dropped
+                    if (group << shift) >> shift != group {
+                        return Err(ToFromByteError::InvalidValue);
+                    }
+
+                    value |= group << shift;
+
+                    if byte & 0x80 == 0 {
+                        // A zero last byte could have been left off entirely
+                        if byte == 0 && index > 0 {
+                            return Err(ToFromByteError::InvalidValue);
+                        }
+
+                        return Ok(($name(value), reader.pos));
+                    }
+                }
+
+                Err(ToFromByteError::InvalidValue)
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                let bits = (<$int>::BITS - self.0.leading_zeros()) as usize;
+
+                bits.div_ceil(7).max(1)
+            }
+        }
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for the signed zigzag var-int wrappers.
+///
+/// Zigzag maps signed to unsigned (0, -1, 1, -2, ... to 0, 1, 2, 3, ...) so small negative
+/// numbers stay small, then the unsigned LEB128 wrapper `$var` does the actual encoding.
+#[macro_export]
+macro_rules! to_from_bytes_zigzag {
+    ($name: ident, $int: ty, $var: ident) => {
+        impl $name {
+            #[inline(always)]
+            fn zigzag(self) -> $var {
+                $var(((self.0 << 1) ^ (self.0 >> (<$int>::BITS - 1))) as _)
+            }
+        }
+
+        impl ToFromBytes<'_> for $name {
+            const MAX_BYTES: usize = $var::MAX_BYTES;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write(&self.zigzag())
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let $var(value) = reader.read()?;
+
+                Ok(($name((value >> 1) as $int ^ -((value & 1) as $int)), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                self.zigzag().byte_count()
+            }
+        }
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations and conversions for `Fixed<$int, SCALE>`.
+///
+/// - The mantissa is written as a plain `$int`, so the wire format is the bare integer
+/// - `$int::MIN` is excluded from the valid range so negation and `unsigned_abs` can never overflow
+/// - `FACTOR` is `10^SCALE`, converting with a `SCALE` too large for `$int` fails to compile
+#[macro_export]
+macro_rules! to_from_bytes_fixed {
+    ($int: ty, $uint: ty) => {
+        impl<const SCALE: u32> Fixed<$int, SCALE> {
+            /// `10^SCALE`, the number of mantissa steps per whole unit.
+            pub const FACTOR: $int = <$int>::pow(10, SCALE);
+            /// The smallest representable value.
+            pub const MIN: Self = Fixed(-<$int>::MAX);
+            /// The largest representable value.
+            pub const MAX: Self = Fixed(<$int>::MAX);
+
+            /// Creates a value from its raw mantissa, `None` if it is out of range.
+            #[inline(always)]
+            pub const fn from_mantissa(mantissa: $int) -> Option<Self> {
+                if mantissa == <$int>::MIN {
+                    return None;
+                }
+
+                Some(Fixed(mantissa))
+            }
+
+            /// Returns the raw mantissa, the value multiplied by `FACTOR`.
+            #[inline(always)]
+            pub const fn mantissa(self) -> $int {
+                self.0
+            }
+
+            /// Converts a whole number, `None` if it doesn't fit once scaled.
+            #[inline(always)]
+            pub fn from_int(value: $int) -> Option<Self> {
+                value.checked_mul(Self::FACTOR).and_then(Self::from_mantissa)
+            }
+
+            /// Returns the whole part, truncating the fraction toward zero.
+            #[inline(always)]
+            pub const fn to_int(self) -> $int {
+                self.0 / Self::FACTOR
+            }
+
+            /// Converts a float, rounding half away from zero to the nearest step.
+            ///
+            /// `None` for NaN, infinity and values outside `MIN..=MAX`.
+            #[inline(always)]
+            pub fn from_f64(value: f64) -> Option<Self> {
+                let mut factor = 1.0;
+
+                for _i in 0..SCALE {
+                    factor *= 10.0;
+                }
+
+                let scaled = value * factor;
+
+                // `MIN` is a power of two and exact as a float, truncating anything strictly between
+                // `MIN` and `-MIN` lands in range. Written as a negated check so NaN is rejected too.
+                let limit = -(<$int>::MIN as f64);
+
+                if !(scaled > -limit && scaled < limit) {
+                    return None;
+                }
+
+                // Truncate first and round on the exact remainder, adding 0.5 before truncating
+                // rounds twice and turns e.g. 0.49999999999999994 into 1.
+                let truncated = scaled as $int;
+                let fraction = scaled - truncated as f64;
+
+                let rounded = if fraction >= 0.5 {
+                    truncated.checked_add(1)?
+                } else if fraction <= -0.5 {
+                    truncated.checked_sub(1)?
+                } else {
+                    truncated
+                };
+
+                Self::from_mantissa(rounded)
+            }
+
+            /// Converts to the nearest float. Precision is lost for mantissas beyond the float's 53 bits.
+            #[inline(always)]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / Self::FACTOR as f64
+            }
+        }
+
+        impl<const SCALE: u32> fmt::Display for Fixed<$int, SCALE> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let sign = if self.0 < 0 { "-" } else { "" };
+                let magnitude = self.0.unsigned_abs();
+                let factor = Self::FACTOR as $uint;
+
+                write!(formatter, "{}{}", sign, magnitude / factor)?;
+
+                if SCALE > 0 {
+                    write!(formatter, ".{:0width$}", magnitude % factor, width = SCALE as usize)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl<const SCALE: u32> ToFromBytes<'_> for Fixed<$int, SCALE> {
+            const MAX_BYTES: usize = <$int>::MAX_BYTES;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write(&self.0)
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let mantissa: $int = reader.read()?;
+                let value = Self::from_mantissa(mantissa).ok_or(ToFromByteError::InvalidValue)?;
+
+                Ok((value, reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                <$int>::MAX_BYTES
+            }
+        }
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for the `core::num::NonZero*` integers.
+///
+/// The wire format is identical to the underlying integer, a zero is rejected with `InvalidValue`.
+#[macro_export]
+macro_rules! to_from_bytes_nonzero {
+    ($nonzero: ty, $int: ty) => {
+        impl ToFromBytes<'_> for $nonzero {
+            const MAX_BYTES: usize = <$int>::MAX_BYTES;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write(&self.get())
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let value: $int = reader.read()?;
+                let value = <$nonzero>::new(value).ok_or(ToFromByteError::InvalidValue)?;
+
+                Ok((value, reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                <$int>::MAX_BYTES
+            }
         }
     };
 }
@@ -75,3 +616,116 @@ macro_rules! to_from_bytes_tuple {
         }
     };
 }
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for IEEE 754 floats (f32, f64).
+///
+/// Floats are serialized through `to_bits`, so the exact bit pattern goes on the wire.
+///
+/// - Every float is serialized in big-endian, same as the integers
+/// - NaN payloads and the sign of zero are preserved as-is, see `Canonical` and `Finite` for stricter policies
+#[macro_export]
+macro_rules! to_from_bytes_float {
+    ($float: ty, $bits: ty, $byte_count: literal) => {
+        impl ToFromBytes<'_> for $float {
+            const MAX_BYTES: usize = $byte_count;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write_bytes(&self.to_bits().to_be_bytes())
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let bytes = reader.read_bytes($byte_count)?;
+                let bytes = bytes.try_into().map_err(|_| ToFromByteError::NotEnoughBytes)?;
+
+                Ok((<$float>::from_bits(<$bits>::from_be_bytes(bytes)), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                $byte_count
+            }
+        }
+    };
+}
+
+/// This is an internal macro not intended for use outside of this crate.
+///
+/// Generates `ToFromBytes` implementations for the `Canonical` and `Finite` float wrappers.
+///
+/// Both wrappers use the same layout as the bare float, they only differ in which values they accept.
+#[macro_export]
+macro_rules! to_from_bytes_float_policy {
+    ($float: ty, $byte_count: literal) => {
+        impl Canonical<$float> {
+            /// Collapses every NaN to `NAN` and `-0.0` to `0.0`, leaves all other values untouched.
+            #[inline(always)]
+            fn canonical(value: $float) -> $float {
+                if value.is_nan() {
+                    <$float>::NAN
+                } else if value == 0.0 {
+                    0.0
+                } else {
+                    value
+                }
+            }
+        }
+
+        impl ToFromBytes<'_> for Canonical<$float> {
+            const MAX_BYTES: usize = $byte_count;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                writer.write(&Self::canonical(self.0))
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let value: $float = reader.read()?;
+
+                if value.to_bits() != Self::canonical(value).to_bits() {
+                    return Err(ToFromByteError::InvalidValue);
+                }
+
+                Ok((Canonical(value), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                $byte_count
+            }
+        }
+
+        impl ToFromBytes<'_> for Finite<$float> {
+            const MAX_BYTES: usize = $byte_count;
+
+            #[inline(always)]
+            fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+                if !self.0.is_finite() {
+                    return Err(ToFromByteError::InvalidValue);
+                }
+
+                writer.write(&Canonical(self.0))
+            }
+
+            #[inline(always)]
+            fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+                let Canonical(value): Canonical<$float> = reader.read()?;
+
+                if !value.is_finite() {
+                    return Err(ToFromByteError::InvalidValue);
+                }
+
+                Ok((Finite(value), reader.pos))
+            }
+
+            #[inline(always)]
+            fn byte_count(&self) -> usize {
+                $byte_count
+            }
+        }
+    };
+}
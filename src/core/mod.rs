@@ -10,23 +10,52 @@
 
 /// The public API intended for consumption
 pub mod api;
+/// Bit-level writer and reader for packed sub-byte fields.
+pub mod bits;
+/// Fixed-capacity containers that work without allocation.
+pub mod bounded;
+/// Runtime configuration carried by readers and writers.
+pub mod config;
 /// ToFromBytes trait implementations for container types.
 pub mod containers;
+/// Little-endian and explicit big-endian wrapper types.
+pub mod endian;
 /// Contains all error states for the crate.
 pub mod error;
-/// Helper macros used internally to implement `ToFromBytes` for integers and tuples.
+/// Decimal fixed-point numbers built on the integer encodings.
+pub mod fixed;
+/// Opt-in NaN and signed-zero policies for floats.
+pub mod floats;
+/// Helper macros used internally to implement `ToFromBytes` for integers, floats and tuples.
 mod macros;
+/// ToFromBytes trait implementations for `core::net` address types.
+pub mod net;
+/// Odd-width integer newtypes (24 and 48 bits).
+pub mod odd_ints;
+/// Wrapper for choosing the length-prefix width of strings and collections.
+pub mod prefixed;
 /// ToFromBytes trait implementations for primitive types.
 pub mod primitives;
 /// Used for traversing a byte slice for reading.
 pub mod reader;
 /// The trait used for serialization, implement the trait for serialization support.
 pub mod to_from_bytes;
+/// Opt-in LEB128 and zigzag variable-length integers.
+pub mod varint;
 /// Used for traversing a byte slice for writing.
 pub mod writer;
 
-pub use api::{from_bytes, read_bytes, write_bytes};
+pub use api::{from_bytes, padding_for, read_bytes, write_bytes};
+pub use bits::{BitReader, BitWriter};
+pub use bounded::{BoundedString, BoundedVec};
+pub use config::Config;
+pub use endian::{Be, Le};
 pub use error::ToFromByteError;
-pub use reader::BytesReader;
+pub use fixed::{Decimal, Fixed};
+pub use floats::{Canonical, Finite};
+pub use odd_ints::{I24, I48, U24, U48};
+pub use prefixed::{LengthPrefix, Prefixed};
+pub use reader::{BytesReader, SubReader};
 pub use to_from_bytes::ToFromBytes;
+pub use varint::{VarI128, VarI16, VarI32, VarI64, VarU128, VarU16, VarU32, VarU64};
 pub use writer::BytesWriter;
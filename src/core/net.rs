@@ -0,0 +1,192 @@
+//! Implementations for the `core::net` address types.
+//!
+//! The layout is stable and documented on each implementation:
+//! - Addresses are written as their raw octets, in network order.
+//! - Ports, flow info and scope ids are big-endian integers, same as everywhere else.
+//! - `IpAddr` and `SocketAddr` are prefixed with a family tag: `4` for IPv4, `6` for IPv6.
+//!   Any other tag is rejected with `InvalidValue`.
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::{BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+/// Serialized as its 4 octets.
+impl ToFromBytes<'_> for Ipv4Addr {
+    const MAX_BYTES: usize = 4;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write_bytes(&self.octets())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let octets: [u8; 4] = reader.read()?;
+
+        Ok((Ipv4Addr::from(octets), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4
+    }
+}
+
+/// Serialized as its 16 octets.
+impl ToFromBytes<'_> for Ipv6Addr {
+    const MAX_BYTES: usize = 16;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write_bytes(&self.octets())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let octets: [u8; 16] = reader.read()?;
+
+        Ok((Ipv6Addr::from(octets), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        16
+    }
+}
+
+/// Serialized as a family tag (`4` or `6`) followed by the address.
+impl ToFromBytes<'_> for IpAddr {
+    const MAX_BYTES: usize = 1 + Ipv6Addr::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        match self {
+            IpAddr::V4(addr) => {
+                writer.write(&4u8)?;
+                writer.write(addr)?;
+            }
+            IpAddr::V6(addr) => {
+                writer.write(&6u8)?;
+                writer.write(addr)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let family: u8 = reader.read()?;
+
+        match family {
+            4 => Ok((IpAddr::V4(reader.read()?), reader.pos)),
+            6 => Ok((IpAddr::V6(reader.read()?), reader.pos)),
+            _ => Err(ToFromByteError::InvalidValue),
+        }
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        match self {
+            IpAddr::V4(addr) => 1 + addr.byte_count(),
+            IpAddr::V6(addr) => 1 + addr.byte_count(),
+        }
+    }
+}
+
+/// Serialized as the 4 address octets followed by the `u16` port (6 bytes).
+impl ToFromBytes<'_> for SocketAddrV4 {
+    const MAX_BYTES: usize = 6;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write(self.ip())?;
+        writer.write(&self.port())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let ip = reader.read()?;
+        let port = reader.read()?;
+
+        Ok((SocketAddrV4::new(ip, port), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        6
+    }
+}
+
+/// Serialized as the 16 address octets, the `u16` port, the `u32` flow info
+/// and the `u32` scope id (26 bytes).
+impl ToFromBytes<'_> for SocketAddrV6 {
+    const MAX_BYTES: usize = 26;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write(self.ip())?;
+        writer.write(&self.port())?;
+        writer.write(&self.flowinfo())?;
+        writer.write(&self.scope_id())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let ip = reader.read()?;
+        let port = reader.read()?;
+        let flowinfo = reader.read()?;
+        let scope_id = reader.read()?;
+
+        Ok((SocketAddrV6::new(ip, port, flowinfo, scope_id), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        26
+    }
+}
+
+/// Serialized as a family tag (`4` or `6`) followed by the socket address.
+impl ToFromBytes<'_> for SocketAddr {
+    const MAX_BYTES: usize = 1 + SocketAddrV6::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        match self {
+            SocketAddr::V4(addr) => {
+                writer.write(&4u8)?;
+                writer.write(addr)?;
+            }
+            SocketAddr::V6(addr) => {
+                writer.write(&6u8)?;
+                writer.write(addr)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let family: u8 = reader.read()?;
+
+        match family {
+            4 => Ok((SocketAddr::V4(reader.read()?), reader.pos)),
+            6 => Ok((SocketAddr::V6(reader.read()?), reader.pos)),
+            _ => Err(ToFromByteError::InvalidValue),
+        }
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        match self {
+            SocketAddr::V4(addr) => 1 + addr.byte_count(),
+            SocketAddr::V6(addr) => 1 + addr.byte_count(),
+        }
+    }
+}
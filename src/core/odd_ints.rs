@@ -0,0 +1,28 @@
+//! Odd-width integers for hardware registers and legacy protocols.
+//!
+//! Each newtype wraps the next native integer up and is written big-endian in exactly 3 or 6 bytes.
+//! Encoding a value outside `MIN..=MAX` fails with `InvalidValue` instead of silently truncating.
+//! Decoding the signed variants sign-extends, so `I24(-1)` round-trips as `-1`.
+
+use crate::{to_from_bytes_odd_int, BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+/// Unsigned 24-bit integer, written as 3 big-endian bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct U24(pub u32);
+
+/// Signed 24-bit integer, written as 3 big-endian bytes in two's complement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct I24(pub i32);
+
+/// Unsigned 48-bit integer, written as 6 big-endian bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct U48(pub u64);
+
+/// Signed 48-bit integer, written as 6 big-endian bytes in two's complement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct I48(pub i64);
+
+to_from_bytes_odd_int!(U24, u32, 3);
+to_from_bytes_odd_int!(I24, i32, 3);
+to_from_bytes_odd_int!(U48, u64, 6);
+to_from_bytes_odd_int!(I48, i64, 6);
@@ -0,0 +1,182 @@
+//! Selectable length-prefix width for strings and collections.
+//!
+//! `&str`, `String`, `&[u8]` and `Vec<T>` use a `u32` length prefix by default. That wastes 3 bytes
+//! on every short name, and can't match protocols that use `u8` or `u16` lengths.
+//!
+//! Wrap the field in `Prefixed<P, T>` to pick the prefix per field, where `P` is one of
+//! `u8`, `u16`, `u32` or `VarU32`. Only the prefix changes, the contents are written exactly as before.
+//! Encoding a length that doesn't fit in `P` fails with `MaxBytesExceeded`.
+//!
+//! ```rust
+//! use minbin::{from_bytes, to_bytes, Prefixed};
+//!
+//! let name: Prefixed<u8, String> = Prefixed::new("minbin".to_string());
+//! let bytes = to_bytes(&name).unwrap();
+//!
+//! assert_eq!(b"\x06minbin", bytes.as_slice());
+//! assert_eq!(name, from_bytes(&bytes).unwrap());
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::{BytesReader, BytesWriter, ToFromByteError, ToFromBytes, VarU32};
+
+/// An integer type that can be used as a length prefix.
+pub trait LengthPrefix: Sized {
+    /// Converts a length into the prefix, failing with `MaxBytesExceeded` when it doesn't fit.
+    fn from_len(len: usize) -> Result<Self, ToFromByteError>;
+
+    /// Converts the prefix back into a length.
+    fn into_len(self) -> usize;
+}
+
+impl LengthPrefix for u8 {
+    #[inline(always)]
+    fn from_len(len: usize) -> Result<Self, ToFromByteError> {
+        u8::try_from(len).map_err(|_| ToFromByteError::MaxBytesExceeded)
+    }
+
+    #[inline(always)]
+    fn into_len(self) -> usize {
+        self as usize
+    }
+}
+
+impl LengthPrefix for u16 {
+    #[inline(always)]
+    fn from_len(len: usize) -> Result<Self, ToFromByteError> {
+        u16::try_from(len).map_err(|_| ToFromByteError::MaxBytesExceeded)
+    }
+
+    #[inline(always)]
+    fn into_len(self) -> usize {
+        self as usize
+    }
+}
+
+impl LengthPrefix for u32 {
+    #[inline(always)]
+    fn from_len(len: usize) -> Result<Self, ToFromByteError> {
+        u32::try_from(len).map_err(|_| ToFromByteError::MaxBytesExceeded)
+    }
+
+    #[inline(always)]
+    fn into_len(self) -> usize {
+        self as usize
+    }
+}
+
+impl LengthPrefix for VarU32 {
+    #[inline(always)]
+    fn from_len(len: usize) -> Result<Self, ToFromByteError> {
+        u32::try_from(len).map(VarU32).map_err(|_| ToFromByteError::MaxBytesExceeded)
+    }
+
+    #[inline(always)]
+    fn into_len(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Writes `value` with a `P` length prefix instead of the default `u32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Prefixed<P, T> {
+    /// The wrapped string or collection.
+    pub value: T,
+    prefix: PhantomData<P>,
+}
+
+impl<P, T> Prefixed<P, T> {
+    /// Wraps a value.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self { value, prefix: PhantomData }
+    }
+
+    /// Returns the wrapped value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the number of bytes the prefix takes for a length of `len`.
+    ///
+    /// A length that doesn't fit counts as `P::MAX_BYTES`, writing it fails anyway.
+    #[inline(always)]
+    pub fn prefix_byte_count<'a>(len: usize) -> usize
+    where
+        P: LengthPrefix + ToFromBytes<'a>,
+    {
+        P::from_len(len).map(|prefix| prefix.byte_count()).unwrap_or(P::MAX_BYTES)
+    }
+}
+
+impl<P, T> Deref for Prefixed<P, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<'a, P> ToFromBytes<'a> for Prefixed<P, &'a str>
+where
+    P: LengthPrefix + ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = <&'a str>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len_with::<Self, P>(self.value.len())?;
+        writer.write_bytes(self.value.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len_with::<Self, P>()?;
+
+        let bytes = reader.read_bytes(len)?;
+
+        let value = core::str::from_utf8(bytes).map_err(|_| ToFromByteError::InvalidValue)?;
+
+        Ok((Prefixed::new(value), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        Self::prefix_byte_count(self.value.len()) + self.value.len()
+    }
+}
+
+impl<'a, P> ToFromBytes<'a> for Prefixed<P, &'a [u8]>
+where
+    P: LengthPrefix + ToFromBytes<'a>,
+{
+    const MAX_BYTES: usize = <&'a [u8]>::MAX_BYTES;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len_with::<Self, P>(self.value.len())?;
+        writer.write_bytes(self.value)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len_with::<Self, P>()?;
+
+        let value = reader.read_bytes(len)?;
+
+        Ok((Prefixed::new(value), reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        Self::prefix_byte_count(self.value.len()) + self.value.len()
+    }
+}
@@ -1,9 +1,17 @@
-//! Implementations for fixed-width primitives and `bool`.
+//! Implementations for fixed-width primitives, `bool` and `char`.
 //!
 //! All integers are serialized in big-endian byte order using `to_be_bytes`/`from_be_bytes`.
+//! Floats are serialized the same way through `to_bits`/`from_bits`.
+//! `usize`/`isize` are always 8 bytes, as `u64`/`i64`, so the wire format doesn't depend on the platform.
+//! The `NonZero*` integers share the layout of their underlying integer and reject zero on decode.
 //! Fixed-width types ensure the size is always known at compile time, meaning less errors for us to deal with.
 
-use crate::{to_from_bytes_int, BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+
+use crate::{
+    to_from_bytes_float, to_from_bytes_int, to_from_bytes_nonzero, to_from_bytes_size, BytesReader, BytesWriter, ToFromByteError,
+    ToFromBytes,
+};
 
 impl ToFromBytes<'_> for bool {
     const MAX_BYTES: usize = 1;
@@ -30,7 +38,48 @@ impl ToFromBytes<'_> for bool {
     }
 }
 
-to_from_bytes_int!(u8, 1);
+/// Serialized as its Unicode scalar value in a fixed 4-byte big-endian `u32`.
+///
+/// Fixed width over UTF-8 keeps the size known at compile time, same as the integers.
+impl ToFromBytes<'_> for char {
+    const MAX_BYTES: usize = 4;
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write(&u32::from(*self))
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'_>) -> Result<(Self, usize), ToFromByteError> {
+        let scalar: u32 = reader.read()?;
+
+        // Rejects surrogates (0xD800..=0xDFFF) and anything above 0x10FFFF
+        let value = char::from_u32(scalar).ok_or(ToFromByteError::InvalidValue)?;
+
+        Ok((value, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        4
+    }
+}
+
+// A `u8` is its own wire format, so runs of bytes are copied in one go instead of byte by byte.
+to_from_bytes_int!(
+    u8,
+    1,
+    #[inline(always)]
+    fn write_slice(values: &[Self], writer: &mut BytesWriter<'_>) -> Result<(), ToFromByteError> {
+        writer.write_bytes(values)
+    },
+    #[inline(always)]
+    fn read_into<C: Extend<Self>>(reader: &mut BytesReader<'_>, count: usize, out: &mut C) -> Result<(), ToFromByteError> {
+        out.extend(reader.read_bytes(count)?.iter().copied());
+
+        Ok(())
+    }
+);
 to_from_bytes_int!(u16, 2);
 to_from_bytes_int!(u32, 4);
 to_from_bytes_int!(u64, 8);
@@ -41,3 +90,21 @@ to_from_bytes_int!(i16, 2);
 to_from_bytes_int!(i32, 4);
 to_from_bytes_int!(i64, 8);
 to_from_bytes_int!(i128, 16);
+
+to_from_bytes_size!(usize, u64);
+to_from_bytes_size!(isize, i64);
+
+to_from_bytes_nonzero!(NonZeroU8, u8);
+to_from_bytes_nonzero!(NonZeroU16, u16);
+to_from_bytes_nonzero!(NonZeroU32, u32);
+to_from_bytes_nonzero!(NonZeroU64, u64);
+to_from_bytes_nonzero!(NonZeroU128, u128);
+
+to_from_bytes_nonzero!(NonZeroI8, i8);
+to_from_bytes_nonzero!(NonZeroI16, i16);
+to_from_bytes_nonzero!(NonZeroI32, i32);
+to_from_bytes_nonzero!(NonZeroI64, i64);
+to_from_bytes_nonzero!(NonZeroI128, i128);
+
+to_from_bytes_float!(f32, u32, 4);
+to_from_bytes_float!(f64, u64, 8);
@@ -5,7 +5,9 @@
 //! Because manually slicing and passing indices leads to off-by-one bugs and lifetime hell.
 //! This wrapper simplifies working with the Rust compiler and introduces zero overhead.
 
-use crate::{ToFromByteError, ToFromBytes};
+use core::ops::{Deref, DerefMut};
+
+use crate::{padding_for, Config, LengthPrefix, ToFromByteError, ToFromBytes};
 
 /// Reads from an immutable byte slice.
 pub struct BytesReader<'a> {
@@ -13,13 +15,21 @@ pub struct BytesReader<'a> {
     pub data: &'a [u8],
     /// Current read position. Always ≤ data.len().
     pub pos: usize,
+    /// Runtime limits, strictness and protocol version. Readable from inside `from_bytes`.
+    pub config: Config,
 }
 
 impl<'a> BytesReader<'a> {
-    /// Create a new reader starting at position 0.
+    /// Create a new reader starting at position 0, using `Config::DEFAULT`.
     #[inline(always)]
     pub const fn new(data: &'a [u8]) -> Self {
-        Self { data, pos: 0 }
+        Self::with_config(data, Config::DEFAULT)
+    }
+
+    /// Create a new reader starting at position 0, using the given configuration.
+    #[inline(always)]
+    pub const fn with_config(data: &'a [u8], config: Config) -> Self {
+        Self { data, pos: 0, config }
     }
 
     /// Convenience function.
@@ -59,12 +69,199 @@ impl<'a> BytesReader<'a> {
         Ok(slice)
     }
 
+    /// Reads a `u32` length prefix for the length-prefixed container `C`, advancing the cursor.
+    ///
+    /// Fails with `MaxBytesExceeded` if the claimed length is larger than `C::MAX_BYTES`
+    /// or the configured `max_len`.
+    /// Every element takes at least one byte (zero-sized ones still cost a loop iteration),
+    /// so a bigger length can never be valid and is rejected before any work is done.
+    /// `BytesWriter::write_len` rejects the same lengths when encoding.
+    ///
+    /// Pair it with `capacity_for` before preallocating:
+    ///
+    /// ```rust
+    /// # use minbin::{BytesReader, ToFromByteError};
+    /// # fn main() -> Result<(), ToFromByteError> {
+    /// let mut reader = BytesReader::new(&[0, 0, 0, 2, 0, 1, 0, 2]);
+    ///
+    /// let len = reader.read_len::<Vec<u16>>()?;
+    /// let mut values: Vec<u16> = Vec::with_capacity(reader.capacity_for::<u16>(len));
+    ///
+    /// for _i in 0..len {
+    ///     values.push(reader.read()?);
+    /// }
+    ///
+    /// assert_eq!(values, [1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn read_len<C: ToFromBytes<'a>>(&mut self) -> Result<usize, ToFromByteError> {
+        self.read_len_with::<C, u32>()
+    }
+
+    /// Same as `read_len`, but reads the length prefix as `P` instead of `u32`.
+    ///
+    /// Used by `Prefixed<P, T>` to support `u8`, `u16`, `u32` and var-int length prefixes.
+    #[inline(always)]
+    pub fn read_len_with<C: ToFromBytes<'a>, P: LengthPrefix + ToFromBytes<'a>>(&mut self) -> Result<usize, ToFromByteError> {
+        let len = self.read::<P>()?.into_len();
+
+        if len > C::MAX_BYTES || len > self.config.max_len {
+            return Err(ToFromByteError::MaxBytesExceeded);
+        }
+
+        Ok(len)
+    }
+
+    /// Returns how many `T` elements are safe to preallocate for a claimed length of `len`.
+    ///
+    /// A length prefix comes straight from the wire, so trusting it lets a 5-byte packet
+    /// request gigabytes of memory. The result is capped so the allocation never exceeds
+    /// the bytes remaining in the reader. Containers still grow normally past the hint if
+    /// the elements are smaller on the wire than in memory.
+    #[inline(always)]
+    pub fn capacity_for<T>(&self, len: usize) -> usize {
+        len.min(self.remaining() / core::mem::size_of::<T>().max(1))
+    }
+
+    /// Number of bytes left to read.
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    /// Returns `true` if every byte has been read.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Reads a value without advancing the cursor.
+    ///
+    /// For dispatching on a discriminant before handing the reader to the matching type.
+    #[inline(always)]
+    pub fn peek<T: ToFromBytes<'a>>(&mut self) -> Result<T, ToFromByteError> {
+        let start_pos = self.pos;
+
+        let result = self.read::<T>();
+
+        self.pos = start_pos;
+
+        result
+    }
+
+    /// Skip `byte_count` bytes without looking at them, advancing the cursor.
+    ///
+    /// For sections you don't understand, like fields added by a newer protocol version.
+    #[inline(always)]
+    pub fn skip(&mut self, byte_count: usize) -> Result<(), ToFromByteError> {
+        self.read_bytes(byte_count).map(|_| ())
+    }
+
+    /// Borrow the next `len` bytes as a separate reader, for length-delimited sub-messages.
+    ///
+    /// The sub-reader can't read past `len` and shares the parent's config. It keeps the parent's
+    /// positions, so `align_to` and the positions returned by `from_bytes` stay relative to the start of the buffer.
+    /// The parent is advanced by `len` once `SubReader::finish` succeeds.
+    ///
+    /// Fails with `NotEnoughBytes` if fewer than `len` bytes remain.
+    ///
+    /// ```rust
+    /// # use minbin::{BytesReader, ToFromByteError};
+    /// # fn main() -> Result<(), ToFromByteError> {
+    /// let mut reader = BytesReader::new(&[2, 0, 1, 9]);
+    ///
+    /// let len = reader.read::<u8>()?;
+    /// let mut sub = reader.sub_reader(len as usize)?;
+    /// let value = sub.read::<u16>()?;
+    /// sub.finish()?;
+    ///
+    /// assert_eq!(value, 1);
+    /// assert_eq!(reader.read::<u8>()?, 9);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn sub_reader(&mut self, len: usize) -> Result<SubReader<'_, 'a>, ToFromByteError> {
+        self.assert_enough_bytes(len)?;
+
+        let inner = BytesReader { data: &self.data[..self.pos + len], pos: self.pos, config: self.config };
+
+        Ok(SubReader { parent: self, inner })
+    }
+
+    /// Skip `byte_count` padding bytes, advancing the cursor.
+    ///
+    /// Padding is expected to be zero. With `config.strict` any other byte fails with `InvalidValue`,
+    /// otherwise the contents are ignored so data from lenient writers still decodes.
+    #[inline(always)]
+    pub fn skip_padding(&mut self, byte_count: usize) -> Result<(), ToFromByteError> {
+        let padding = self.read_bytes(byte_count)?;
+
+        if self.config.strict && padding.iter().any(|byte| *byte != 0) {
+            return Err(ToFromByteError::InvalidValue);
+        }
+
+        Ok(())
+    }
+
+    /// Skip padding until `pos` is a multiple of `align`, the counterpart of `BytesWriter::align_to`.
+    ///
+    /// Checks the padding the same way as `skip_padding`.
+    #[inline(always)]
+    pub fn align_to(&mut self, align: usize) -> Result<(), ToFromByteError> {
+        self.skip_padding(padding_for(self.pos, align))
+    }
+
     #[inline(always)]
     fn assert_enough_bytes(&self, byte_count: usize) -> Result<(), ToFromByteError> {
-        if self.pos + byte_count > self.data.len() {
+        if byte_count > self.remaining() {
             return Err(ToFromByteError::NotEnoughBytes);
         }
 
         Ok(())
     }
 }
+
+/// A reader confined to a length-delimited part of its parent, created by `BytesReader::sub_reader`.
+///
+/// Derefs to `BytesReader`, so it reads like any other reader.
+/// Call `finish` when done, the parent is not advanced if it is dropped.
+pub struct SubReader<'r, 'a> {
+    parent: &'r mut BytesReader<'a>,
+    inner: BytesReader<'a>,
+}
+
+impl<'r, 'a> SubReader<'r, 'a> {
+    /// Advance the parent past the sub-message.
+    ///
+    /// Fails with `TrailingBytes` if the sub-message wasn't fully consumed, same as `from_bytes`.
+    /// Use `skip` first to deliberately ignore the rest.
+    #[inline(always)]
+    pub fn finish(self) -> Result<(), ToFromByteError> {
+        if !self.inner.is_empty() {
+            return Err(ToFromByteError::TrailingBytes);
+        }
+
+        self.parent.pos = self.inner.pos;
+
+        Ok(())
+    }
+}
+
+impl<'r, 'a> Deref for SubReader<'r, 'a> {
+    type Target = BytesReader<'a>;
+
+    #[inline(always)]
+    fn deref(&self) -> &BytesReader<'a> {
+        &self.inner
+    }
+}
+
+impl<'r, 'a> DerefMut for SubReader<'r, 'a> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut BytesReader<'a> {
+        &mut self.inner
+    }
+}
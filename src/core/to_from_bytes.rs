@@ -16,6 +16,9 @@ use crate::{BytesReader, BytesWriter};
 /// - `byte_count`: Enables preallocation and exact buffer sizing. Critical for no-std/stack buffers.
 /// - `to_bytes`:   Writes into an existing buffer. No intermediate allocations.
 /// - `from_bytes`: Returns the final cursor position. You can parse multiple messages from one buffer.
+///
+/// `write_slice` and `read_into` also exist, with default implementations. They only serve as the
+/// bulk path for `u8` inside length-prefixed containers, you never override them.
 pub trait ToFromBytes<'a> {
     /// Hard upper bound on the serialized size of this type (including length prefixes
     /// and all nested data).
@@ -58,4 +61,36 @@ pub trait ToFromBytes<'a> {
     ///
     /// The total byte_count including recursive types and length prefixes may not exceed usize.
     fn byte_count(&self) -> usize;
+
+    /// Writes a run of values back to back, without a length prefix.
+    ///
+    /// Used by length-prefixed containers like `Vec<T>`. You never need to implement this,
+    /// the default writes one value at a time. `u8` overrides it with a single `write_bytes`.
+    #[inline(always)]
+    fn write_slice(values: &[Self], writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError>
+    where
+        Self: Sized,
+    {
+        for value in values {
+            writer.write(value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads `count` values back to back into `out`, without a length prefix.
+    ///
+    /// Used by length-prefixed containers like `Vec<T>`. You never need to implement this,
+    /// the default reads one value at a time. `u8` overrides it with a single `read_bytes` and bulk copy.
+    #[inline(always)]
+    fn read_into<C: Extend<Self>>(reader: &mut BytesReader<'a>, count: usize, out: &mut C) -> Result<(), ToFromByteError>
+    where
+        Self: Sized,
+    {
+        for _i in 0..count {
+            out.extend(Some(reader.read()?));
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,55 @@
+//! Opt-in variable-length integers for payloads dominated by small numbers.
+//!
+//! Bare integers stay fixed-width. Wrap a field in one of these types when most values are small
+//! and bytes on the wire matter more than a predictable size, e.g. counters in telemetry.
+//!
+//! - `VarU*` use unsigned LEB128: 7 bits per byte, so values below 128 take a single byte.
+//! - `VarI*` zigzag-encode first, so small negative numbers stay small too.
+//!
+//! Decoding is strict: overlong encodings, bits past the integer width and encodings longer than
+//! `MAX_BYTES` are rejected with `InvalidValue`. Every value has exactly one valid encoding, and
+//! `byte_count()` returns its exact size.
+
+use crate::{to_from_bytes_varint, to_from_bytes_zigzag, BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+/// `u16` as unsigned LEB128, 1 to 3 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarU16(pub u16);
+
+/// `u32` as unsigned LEB128, 1 to 5 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarU32(pub u32);
+
+/// `u64` as unsigned LEB128, 1 to 10 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarU64(pub u64);
+
+/// `u128` as unsigned LEB128, 1 to 19 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarU128(pub u128);
+
+/// `i16` as zigzag LEB128, 1 to 3 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarI16(pub i16);
+
+/// `i32` as zigzag LEB128, 1 to 5 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarI32(pub i32);
+
+/// `i64` as zigzag LEB128, 1 to 10 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarI64(pub i64);
+
+/// `i128` as zigzag LEB128, 1 to 19 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarI128(pub i128);
+
+to_from_bytes_varint!(VarU16, u16, 3);
+to_from_bytes_varint!(VarU32, u32, 5);
+to_from_bytes_varint!(VarU64, u64, 10);
+to_from_bytes_varint!(VarU128, u128, 19);
+
+to_from_bytes_zigzag!(VarI16, i16, VarU16);
+to_from_bytes_zigzag!(VarI32, i32, VarU32);
+to_from_bytes_zigzag!(VarI64, i64, VarU64);
+to_from_bytes_zigzag!(VarI128, i128, VarU128);
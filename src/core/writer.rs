@@ -8,7 +8,7 @@
 //! All writes return early on overflow instead of silently truncating.
 //! Which should prevent otherwise common surprises in production.
 
-use crate::{ToFromByteError, ToFromBytes};
+use crate::{padding_for, Config, LengthPrefix, ToFromByteError, ToFromBytes};
 
 /// Writes into a mutable byte slice.
 ///
@@ -18,13 +18,21 @@ pub struct BytesWriter<'a> {
     pub data: &'a mut [u8],
     /// Current write position. Always ≤ data.len().
     pub pos: usize,
+    /// Runtime limits, strictness and protocol version. Readable from inside `to_bytes`.
+    pub config: Config,
 }
 
 impl<'a> BytesWriter<'a> {
-    /// Create a new writer starting at position 0.
+    /// Create a new writer starting at position 0, using `Config::DEFAULT`.
     #[inline(always)]
     pub const fn new(data: &'a mut [u8]) -> Self {
-        Self { data, pos: 0 }
+        Self::with_config(data, Config::DEFAULT)
+    }
+
+    /// Create a new writer starting at position 0, using the given configuration.
+    #[inline(always)]
+    pub const fn with_config(data: &'a mut [u8], config: Config) -> Self {
+        Self { data, pos: 0, config }
     }
 
     /// Convenience function.
@@ -57,9 +65,56 @@ impl<'a> BytesWriter<'a> {
         Ok(())
     }
 
+    /// Writes a `u32` length prefix for the length-prefixed container `C`, the counterpart of `BytesReader::read_len`.
+    ///
+    /// Fails with `MaxBytesExceeded` for any length `read_len` would reject, so everything
+    /// that encodes also decodes. A `Vec<()>` with millions of elements takes only 4 bytes,
+    /// but its length is still limited by `C::MAX_BYTES`.
+    ///
+    /// The configured `max_len` applies too, give the writer the same config as the peer's reader.
+    #[inline(always)]
+    pub fn write_len<'c, C: ToFromBytes<'c>>(&mut self, len: usize) -> Result<(), ToFromByteError> {
+        self.write_len_with::<C, u32>(len)
+    }
+
+    /// Same as `write_len`, but writes the length prefix as `P` instead of `u32`.
+    ///
+    /// Used by `Prefixed<P, T>`, the counterpart of `BytesReader::read_len_with`.
+    #[inline(always)]
+    pub fn write_len_with<'c, C: ToFromBytes<'c>, P: LengthPrefix + ToFromBytes<'a>>(&mut self, len: usize) -> Result<(), ToFromByteError> {
+        if len > C::MAX_BYTES || len > self.config.max_len {
+            return Err(ToFromByteError::MaxBytesExceeded);
+        }
+
+        self.write(&P::from_len(len)?)
+    }
+
+    /// Write `byte_count` zero bytes.
+    ///
+    /// For reserved fields and explicit padding in fixed layouts.
+    #[inline(always)]
+    pub fn pad(&mut self, byte_count: usize) -> Result<(), ToFromByteError> {
+        self.assert_enough_bytes(byte_count)?;
+
+        self.data[self.pos..self.pos + byte_count].fill(0);
+
+        self.pos += byte_count;
+
+        Ok(())
+    }
+
+    /// Write zero bytes until `pos` is a multiple of `align`.
+    ///
+    /// Alignment is relative to the start of the buffer, not to memory addresses.
+    /// Use `padding_for` to include the padding in `byte_count`.
+    #[inline(always)]
+    pub fn align_to(&mut self, align: usize) -> Result<(), ToFromByteError> {
+        self.pad(padding_for(self.pos, align))
+    }
+
     #[inline(always)]
     fn assert_enough_bytes(&self, byte_count: usize) -> Result<(), ToFromByteError> {
-        if self.pos + byte_count > self.data.len() {
+        if byte_count > self.data.len() - self.pos {
             return Err(ToFromByteError::NotEnoughBytes);
         }
 
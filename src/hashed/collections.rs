@@ -0,0 +1,151 @@
+//! Implementations of `ToFromBytes` for `HashMap` and `HashSet`.
+//!
+//! Both use the same `u32` length prefix and entry layout as `BTreeMap`/`BTreeSet`.
+//!
+//! Iteration order of a hashed collection depends on the hash seed, so writing entries in
+//! iteration order would give different bytes for the same map in different processes.
+//! Instead, entries are sorted by the encoded bytes of their keys before they are written.
+//! The output is deterministic across processes, hash seeds and hasher implementations.
+//!
+//! Decoding accepts entries in any order but rejects duplicate keys with `InvalidValue`.
+
+extern crate alloc;
+extern crate std;
+
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, Hash};
+use std::collections::{HashMap, HashSet};
+
+use crate::{BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+
+impl<'a, K, V, S> ToFromBytes<'a> for HashMap<K, V, S>
+where
+    K: ToFromBytes<'a> + Eq + Hash,
+    V: ToFromBytes<'a>,
+    S: BuildHasher + Default,
+{
+    const MAX_BYTES: usize = 1_048_576; // 1 MiB
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+
+        write_sorted_by_key(writer, self.len(), self.iter())
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let mut map = HashMap::with_capacity_and_hasher(reader.capacity_for::<(K, V)>(len), S::default());
+
+        for _i in 0..len {
+            let key: K = reader.read()?;
+            let value: V = reader.read()?;
+
+            if map.insert(key, value).is_some() {
+                return Err(ToFromByteError::InvalidValue);
+            }
+        }
+
+        Ok((map, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for (key, value) in self.iter() {
+            byte_count += key.byte_count() + value.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+impl<'a, T, S> ToFromBytes<'a> for HashSet<T, S>
+where
+    T: ToFromBytes<'a> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    const MAX_BYTES: usize = 1_048_576; // 1 MiB
+
+    #[inline(always)]
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write_len::<Self>(self.len())?;
+
+        // `()` has no bytes on the wire, so the entries are just the items
+        write_sorted_by_key(writer, self.len(), self.iter().map(|item| (item, &())))
+    }
+
+    #[inline(always)]
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let len = reader.read_len::<Self>()?;
+
+        let mut set = HashSet::with_capacity_and_hasher(reader.capacity_for::<T>(len), S::default());
+
+        for _i in 0..len {
+            if !set.insert(reader.read()?) {
+                return Err(ToFromByteError::InvalidValue);
+            }
+        }
+
+        Ok((set, reader.pos))
+    }
+
+    #[inline(always)]
+    fn byte_count(&self) -> usize {
+        let mut byte_count = 4;
+
+        for item in self.iter() {
+            byte_count += item.byte_count();
+        }
+
+        byte_count
+    }
+}
+
+/// Writes `entries` ordered by the encoded bytes of their keys.
+///
+/// A key only exists as bytes after it has been written, so the entries are first written
+/// in iteration order, then rearranged in place inside the writer buffer.
+#[inline(always)]
+fn write_sorted_by_key<'a, 'b, K, V>(
+    writer: &mut BytesWriter<'a>,
+    len: usize,
+    entries: impl Iterator<Item = (&'b K, &'b V)>,
+) -> Result<(), ToFromByteError>
+where
+    K: ToFromBytes<'a> + 'b,
+    V: ToFromBytes<'a> + 'b,
+{
+    let start = writer.pos;
+
+    // (entry start, key end, entry end) relative to `start`
+    let mut spans = Vec::with_capacity(len);
+
+    for (key, value) in entries {
+        let entry_start = writer.pos - start;
+        writer.write(key)?;
+        let key_end = writer.pos - start;
+        writer.write(value)?;
+
+        spans.push((entry_start, key_end, writer.pos - start));
+    }
+
+    let unsorted = writer.data[start..writer.pos].to_vec();
+
+    spans.sort_unstable_by(|(a_start, a_end, _), (b_start, b_end, _)| unsorted[*a_start..*a_end].cmp(&unsorted[*b_start..*b_end]));
+
+    let mut pos = start;
+
+    for (entry_start, _, entry_end) in spans {
+        let entry = &unsorted[entry_start..entry_end];
+
+        writer.data[pos..pos + entry.len()].copy_from_slice(entry);
+
+        pos += entry.len();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+//! Only compiled when the opt-in `std` feature is active.
+//!
+//! Most services run on std, and `HashMap`/`HashSet` are the collections they reach for.
+//! They live here instead of in `alloc` because the default hasher requires std.
+//!
+//! Implements `HashMap` and `HashSet` with a deterministic encoding.
+
+/// Contains the hashed collection implementations.
+pub mod collections;
@@ -58,7 +58,11 @@
 
 /// Re-exports everything needed for typical usage.
 pub mod core;
-pub use core::{from_bytes, read_bytes, write_bytes, BytesReader, BytesWriter, ToFromByteError, ToFromBytes};
+pub use core::{
+    from_bytes, padding_for, read_bytes, write_bytes, Be, BitReader, BitWriter, BoundedString, BoundedVec, BytesReader, BytesWriter,
+    Canonical, Config, Decimal, Finite, Fixed, Le, LengthPrefix, Prefixed, SubReader, ToFromByteError, ToFromBytes, VarI128, VarI16,
+    VarI32, VarI64, VarU128, VarU16, VarU32, VarU64, I24, I48, U24, U48,
+};
 
 /// Helper macros for implementing `ToFromBytes`.
 pub mod macros;
@@ -68,3 +72,8 @@ pub mod macros;
 pub mod alloc;
 #[cfg(feature = "alloc")]
 pub use alloc::to_bytes;
+
+// Implementations requiring the std crate. Private, it only holds trait impls,
+// and a public root module named `std` would make `std::` paths ambiguous under `use minbin::*`.
+#[cfg(feature = "std")]
+mod hashed;
@@ -0,0 +1,84 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+
+use minbin::{from_bytes, to_bytes, ToFromByteError};
+
+#[test]
+fn test_btree_map() {
+    let expected: BTreeMap<u16, String> = [(3, "c".to_string()), (1, "a".to_string()), (2, "b".to_string())].into();
+
+    let bytes = to_bytes(&expected).unwrap();
+    let actual: BTreeMap<u16, String> = from_bytes(&bytes).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_btree_map_vec_layout() {
+    let expected: BTreeMap<u8, u8> = [(2, 20), (1, 10)].into();
+
+    assert_eq!(to_bytes(&vec![(1u8, 10u8), (2, 20)]).unwrap(), to_bytes(&expected).unwrap());
+}
+
+#[test]
+fn test_btree_map_rejects_unordered() {
+    let unordered = to_bytes(&vec![(2u8, 0u8), (1, 0)]).unwrap();
+    assert_eq!(Err(ToFromByteError::InvalidValue), from_bytes::<BTreeMap<u8, u8>>(&unordered));
+
+    let duplicate = to_bytes(&vec![(1u8, 0u8), (1, 1)]).unwrap();
+    assert_eq!(Err(ToFromByteError::InvalidValue), from_bytes::<BTreeMap<u8, u8>>(&duplicate));
+}
+
+#[test]
+fn test_btree_set() {
+    let expected: BTreeSet<i32> = [5, -1, 3, i32::MIN].into();
+
+    let bytes = to_bytes(&expected).unwrap();
+    let actual: BTreeSet<i32> = from_bytes(&bytes).unwrap();
+
+    assert_eq!(expected, actual);
+
+    let duplicate = to_bytes(&vec![1i32, 1]).unwrap();
+    assert_eq!(Err(ToFromByteError::InvalidValue), from_bytes::<BTreeSet<i32>>(&duplicate));
+}
+
+#[test]
+fn test_vec_deque() {
+    let mut expected: VecDeque<u32> = VecDeque::with_capacity(4);
+    expected.extend([3, 4]);
+    expected.push_front(2);
+    expected.push_front(1);
+
+    let bytes = to_bytes(&expected).unwrap();
+
+    assert_eq!(to_bytes(&vec![1u32, 2, 3, 4]).unwrap(), bytes);
+    assert_eq!(expected, from_bytes::<VecDeque<u32>>(&bytes).unwrap());
+}
+
+#[test]
+fn test_binary_heap() {
+    let expected: BinaryHeap<u16> = [5, 1, 8, 3].into();
+
+    let bytes = to_bytes(&expected).unwrap();
+    let actual: BinaryHeap<u16> = from_bytes(&bytes).unwrap();
+
+    assert_eq!(expected.into_sorted_vec(), actual.into_sorted_vec());
+}
+
+#[test]
+fn test_linked_list() {
+    let expected: LinkedList<String> = ["a".to_string(), "b".to_string()].into();
+
+    let bytes = to_bytes(&expected).unwrap();
+
+    assert_eq!(to_bytes(&vec!["a".to_string(), "b".to_string()]).unwrap(), bytes);
+    assert_eq!(expected, from_bytes::<LinkedList<String>>(&bytes).unwrap());
+}
+
+#[test]
+fn test_queue_untrusted_len() {
+    let result = from_bytes::<VecDeque<u64>>(&[0, 0x0f, 0xff, 0xff, 1]);
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), result);
+
+    let result = from_bytes::<LinkedList<u64>>(&[0xff, 0xff, 0xff, 0xff, 1]);
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), result);
+}
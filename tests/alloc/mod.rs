@@ -1 +1,4 @@
+mod collections;
+mod owned;
+mod pointers;
 mod structs;
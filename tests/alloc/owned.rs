@@ -0,0 +1,98 @@
+use std::ffi::CString;
+
+use minbin::{from_bytes, to_bytes, Prefixed, ToFromByteError};
+
+#[test]
+fn test_vec_u8() {
+    for expected in [vec![], vec![0u8, 1, 2], (0..=255).collect::<Vec<u8>>()] {
+        let bytes = to_bytes(&expected).unwrap();
+
+        assert_eq!(4 + expected.len(), bytes.len());
+        assert_eq!(&expected[..], &bytes[4..]);
+
+        let actual: Vec<u8> = from_bytes(&bytes).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), from_bytes::<Vec<u8>>(&[0, 0, 0, 4, 1, 2, 3]));
+}
+
+#[test]
+fn test_byte_layouts_match() {
+    let expected: &[u8] = b"payload";
+
+    let vec_bytes = to_bytes(&expected.to_vec()).unwrap();
+    let boxed_bytes = to_bytes(&Box::<[u8]>::from(expected)).unwrap();
+
+    assert_eq!(vec_bytes, boxed_bytes);
+    assert_eq!(expected, &*from_bytes::<Box<[u8]>>(&vec_bytes).unwrap());
+}
+
+#[test]
+fn test_cstring() {
+    for expected in [CString::default(), CString::new("firmware").unwrap()] {
+        let bytes = to_bytes(&expected).unwrap();
+
+        assert_eq!(expected.as_bytes_with_nul(), &bytes[..]);
+
+        let actual: CString = from_bytes(&bytes).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), from_bytes::<CString>(b"no terminator"));
+    assert_eq!(Err(ToFromByteError::TrailingBytes), from_bytes::<CString>(b"a\0b"));
+}
+
+#[test]
+fn test_boxed_slice() {
+    let expected: Box<[u32]> = Box::new([1, 2, u32::MAX]);
+
+    let bytes = to_bytes(&expected).unwrap();
+    let actual: Box<[u32]> = from_bytes(&bytes).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_vec_untrusted_len() {
+    // Claims ~1M elements, the cap on preallocation keeps this from allocating before failing
+    let result = from_bytes::<Vec<u64>>(&[0, 0x0f, 0xff, 0xff, 1]);
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), result);
+
+    let result = from_bytes::<Vec<u64>>(&[0xff, 0xff, 0xff, 0xff, 1]);
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), result);
+}
+
+#[test]
+fn test_vec_zero_sized_len() {
+    // Only the 4 byte prefix goes on the wire, the length is still limited so it decodes again
+    let expected = vec![(); 1_000];
+    assert_eq!(expected, from_bytes::<Vec<()>>(&to_bytes(&expected).unwrap()).unwrap());
+
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), to_bytes(&vec![(); 2_000_000]));
+}
+
+#[test]
+fn test_prefixed_string() {
+    let expected: Prefixed<u8, String> = Prefixed::new("name".to_string());
+
+    let bytes = to_bytes(&expected).unwrap();
+
+    assert_eq!(b"\x04name", bytes.as_slice());
+    assert_eq!(expected, from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn test_prefixed_vec() {
+    let expected: Prefixed<u16, Vec<u32>> = Prefixed::new(vec![1, 2, 3]);
+
+    let bytes = to_bytes(&expected).unwrap();
+
+    assert_eq!(2 + 12, bytes.len());
+    assert_eq!(expected, from_bytes(&bytes).unwrap());
+
+    let too_long: Prefixed<u8, Vec<()>> = Prefixed::new(vec![(); 256]);
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), to_bytes(&too_long));
+}
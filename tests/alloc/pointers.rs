@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use minbin::{from_bytes, minbin_enum, read_bytes, to_bytes, write_bytes, ToFromBytes};
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Literal(i64),
+    Add(Box<Expr>, Box<Expr>),
+    Negate(Box<Expr>),
+}
+
+minbin_enum! { Expr [
+    [0 => Self::Literal(value: i64)],
+    [1 => Self::Add(left: Box<Expr>, right: Box<Expr>)],
+    [2 => Self::Negate(inner: Box<Expr>)],
+] }
+
+#[test]
+fn test_recursive_enum() {
+    let expected = Expr::Add(Box::new(Expr::Literal(1)), Box::new(Expr::Negate(Box::new(Expr::Literal(2)))));
+
+    let bytes = to_bytes(&expected).unwrap();
+    let actual: Expr = from_bytes(&bytes).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_pointers_transparent() {
+    let bytes = to_bytes(&42u32).unwrap();
+
+    assert_eq!(bytes, to_bytes(&Box::new(42u32)).unwrap());
+    assert_eq!(bytes, to_bytes(&Rc::new(42u32)).unwrap());
+    assert_eq!(bytes, to_bytes(&Arc::new(42u32)).unwrap());
+
+    assert_eq!(Rc::new(42u32), from_bytes(&bytes).unwrap());
+    assert_eq!(Arc::new(42u32), from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn test_pointer_strs() {
+    let bytes = to_bytes(&"example".to_string()).unwrap();
+
+    assert_eq!(bytes, to_bytes(&Box::<str>::from("example")).unwrap());
+    assert_eq!(bytes, to_bytes(&Rc::<str>::from("example")).unwrap());
+    assert_eq!(bytes, to_bytes(&Arc::<str>::from("example")).unwrap());
+
+    assert_eq!(Box::<str>::from("example"), from_bytes(&bytes).unwrap());
+    assert_eq!(Rc::<str>::from("example"), from_bytes(&bytes).unwrap());
+    assert_eq!(Arc::<str>::from("example"), from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn test_cow_str() {
+    for expected in [Cow::Borrowed("borrowed"), Cow::Owned("owned".to_string())] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Cow<str>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+        assert!(matches!(actual, Cow::Borrowed(_)));
+    }
+}
+
+#[test]
+fn test_cow_bytes() {
+    for expected in [Cow::Borrowed(&b"borrowed"[..]), Cow::Owned(b"owned".to_vec())] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Cow<[u8]>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+        assert!(matches!(actual, Cow::Borrowed(_)));
+    }
+}
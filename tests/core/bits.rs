@@ -0,0 +1,109 @@
+use minbin::{BitReader, BitWriter, BytesReader, BytesWriter, Config, ToFromByteError};
+
+#[test]
+fn test_bits_roundtrip() {
+    let mut buffer = [0u8; 8];
+    let mut writer = BytesWriter::new(&mut buffer);
+
+    let mut bits = BitWriter::new(&mut writer);
+    bits.write_bool(true).unwrap();
+    bits.write_bits(0b101, 3).unwrap();
+    bits.write_bits(0xabc, 12).unwrap();
+    bits.write_signed(-3, 5).unwrap();
+    bits.finish().unwrap();
+
+    // 1 + 3 + 12 + 5 = 21 bits, padded to 3 bytes
+    assert_eq!(writer.pos, 3);
+
+    writer.write(&0xffu8).unwrap();
+
+    assert_eq!(&buffer[..4], &[0b1101_1010, 0b1011_1100, 0b1110_1000, 0xff]);
+
+    let mut reader = BytesReader::new(&buffer[..4]);
+
+    let mut bits = BitReader::new(&mut reader);
+    assert!(bits.read_bool().unwrap());
+    assert_eq!(bits.read_bits(3).unwrap(), 0b101);
+    assert_eq!(bits.read_bits(12).unwrap(), 0xabc);
+    assert_eq!(bits.read_signed(5).unwrap(), -3);
+    bits.finish().unwrap();
+
+    assert_eq!(reader.pos, 3);
+    assert_eq!(reader.read::<u8>().unwrap(), 0xff);
+}
+
+#[test]
+fn test_bits_full_width() {
+    let mut buffer = [0u8; 17];
+    let mut writer = BytesWriter::new(&mut buffer);
+
+    let mut bits = BitWriter::new(&mut writer);
+    bits.write_bits(0, 0).unwrap();
+    bits.write_signed(0, 0).unwrap();
+    bits.write_bool(false).unwrap();
+    bits.write_bits(u64::MAX, 64).unwrap();
+    bits.write_signed(i64::MIN, 64).unwrap();
+    bits.finish().unwrap();
+
+    let mut reader = BytesReader::new(&buffer);
+
+    let mut bits = BitReader::new(&mut reader);
+    assert_eq!(bits.read_bits(0).unwrap(), 0);
+    assert_eq!(bits.read_signed(0).unwrap(), 0);
+    assert!(!bits.read_bool().unwrap());
+    assert_eq!(bits.read_bits(64).unwrap(), u64::MAX);
+    assert_eq!(bits.read_signed(64).unwrap(), i64::MIN);
+    bits.finish().unwrap();
+
+    assert_eq!(reader.pos, 17);
+}
+
+#[test]
+fn test_bits_out_of_range() {
+    let mut buffer = [0u8; 8];
+    let mut writer = BytesWriter::new(&mut buffer);
+    let mut bits = BitWriter::new(&mut writer);
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.write_bits(8, 3));
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.write_bits(0, 65));
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.write_signed(4, 3));
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.write_signed(-5, 3));
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.write_signed(1, 0));
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.write_signed(0, 65));
+
+    let mut reader = BytesReader::new(&buffer);
+    let mut bits = BitReader::new(&mut reader);
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.read_bits(65));
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.read_signed(65));
+}
+
+#[test]
+fn test_bits_not_enough_bytes() {
+    let mut buffer = [0u8; 1];
+    let mut writer = BytesWriter::new(&mut buffer);
+    let mut bits = BitWriter::new(&mut writer);
+
+    bits.write_bits(0x7f, 7).unwrap();
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), bits.write_bits(0, 9));
+
+    let mut reader = BytesReader::new(&buffer);
+    let mut bits = BitReader::new(&mut reader);
+
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), bits.read_bits(9));
+}
+
+#[test]
+fn test_bits_strict_padding() {
+    let data = [0b1010_0001];
+
+    let mut reader = BytesReader::new(&data);
+    let mut bits = BitReader::new(&mut reader);
+    assert_eq!(bits.read_bits(3).unwrap(), 0b101);
+    bits.finish().unwrap();
+
+    let mut reader = BytesReader::with_config(&data, Config { strict: true, ..Config::DEFAULT });
+    let mut bits = BitReader::new(&mut reader);
+    assert_eq!(bits.read_bits(3).unwrap(), 0b101);
+    assert_eq!(Err(ToFromByteError::InvalidValue), bits.finish());
+}
@@ -0,0 +1,76 @@
+use minbin::{read_bytes, write_bytes, BoundedString, BoundedVec, ToFromByteError, ToFromBytes};
+
+#[test]
+fn test_bounded_vec() {
+    for expected in [&[][..], &[1u16][..], &[1, 2, 3, 4]] {
+        let expected: BoundedVec<u16, 4> = BoundedVec::try_from(expected).unwrap();
+
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+
+        let (actual, read_pos): (BoundedVec<u16, 4>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(20, BoundedVec::<u32, 4>::MAX_BYTES);
+}
+
+#[test]
+fn test_bounded_vec_capacity() {
+    let mut bounded: BoundedVec<u8, 2> = BoundedVec::new();
+
+    bounded.push(1).unwrap();
+    bounded.push(2).unwrap();
+
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), bounded.push(3));
+    assert_eq!(Some(2), bounded.pop());
+    assert_eq!(&[1], bounded.as_slice());
+
+    let too_long = [0, 0, 0, 3, 1, 2, 3];
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), read_bytes::<BoundedVec<u8, 2>>(&too_long).map(|(value, _)| value));
+}
+
+#[test]
+fn test_bounded_string() {
+    for expected in ["", "abc", "🦀🦀"] {
+        let expected: BoundedString<8> = BoundedString::try_from(expected).unwrap();
+
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+
+        // Decoded value owns its bytes, the buffer can go away
+        let (actual, read_pos): (BoundedString<8>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+        drop(buffer);
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(12, BoundedString::<8>::MAX_BYTES);
+}
+
+#[test]
+fn test_bounded_string_matches_str() {
+    let expected: BoundedString<16> = BoundedString::try_from("same layout").unwrap();
+
+    let mut bounded_buffer = vec![0u8; expected.byte_count()];
+    write_bytes(&expected, &mut bounded_buffer).unwrap();
+
+    let mut str_buffer = vec![0u8; "same layout".byte_count()];
+    write_bytes(&"same layout", &mut str_buffer).unwrap();
+
+    assert_eq!(str_buffer, bounded_buffer);
+}
+
+#[test]
+fn test_bounded_string_rejects() {
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), BoundedString::<2>::try_from("abc"));
+
+    let too_long = [0, 0, 0, 3, b'a', b'b', b'c'];
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), read_bytes::<BoundedString<2>>(&too_long).map(|(value, _)| value));
+
+    let invalid_utf8 = [0, 0, 0, 1, 0xff];
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<BoundedString<2>>(&invalid_utf8).map(|(value, _)| value));
+}
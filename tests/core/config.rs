@@ -0,0 +1,82 @@
+use minbin::{BytesReader, BytesWriter, Config, ToFromByteError, ToFromBytes};
+
+struct Versioned {
+    id: u32,
+    flags: u8,
+}
+
+impl<'a> ToFromBytes<'a> for Versioned {
+    const MAX_BYTES: usize = 5;
+
+    fn to_bytes(&self, writer: &mut BytesWriter<'a>) -> Result<(), ToFromByteError> {
+        writer.write(&self.id)?;
+
+        if writer.config.version >= 2 {
+            writer.write(&self.flags)?;
+        }
+
+        Ok(())
+    }
+
+    fn from_bytes(reader: &mut BytesReader<'a>) -> Result<(Self, usize), ToFromByteError> {
+        let id = reader.read()?;
+        let flags = if reader.config.version >= 2 { reader.read()? } else { 0 };
+
+        Ok((Versioned { id, flags }, reader.pos))
+    }
+
+    fn byte_count(&self) -> usize {
+        5
+    }
+}
+
+#[test]
+fn test_default_config() {
+    let data = [0u8; 4];
+
+    assert_eq!(Config::DEFAULT, BytesReader::new(&data).config);
+    assert_eq!(Config::default(), Config::DEFAULT);
+}
+
+#[test]
+fn test_config_version() {
+    let config = Config { version: 2, ..Config::DEFAULT };
+
+    let mut buffer = [0u8; 5];
+    let mut writer = BytesWriter::with_config(&mut buffer, config);
+    writer.write(&Versioned { id: 1, flags: 7 }).unwrap();
+
+    assert_eq!(5, writer.pos);
+
+    let mut reader = BytesReader::with_config(&buffer, config);
+    let actual: Versioned = reader.read().unwrap();
+
+    assert_eq!((1, 7), (actual.id, actual.flags));
+
+    let mut reader = BytesReader::new(&buffer);
+    let actual: Versioned = reader.read().unwrap();
+
+    assert_eq!((1, 0), (actual.id, actual.flags));
+    assert_eq!(4, reader.pos);
+}
+
+#[test]
+fn test_config_max_len() {
+    let data = [0, 0, 0, 3, b'a', b'b', b'c'];
+
+    let mut reader = BytesReader::with_config(&data, Config { max_len: 2, ..Config::DEFAULT });
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), reader.read::<&str>());
+
+    let mut reader = BytesReader::with_config(&data, Config { max_len: 3, ..Config::DEFAULT });
+    assert_eq!(Ok("abc"), reader.read::<&str>());
+
+    let mut buffer = [0u8; 7];
+
+    let mut writer = BytesWriter::with_config(&mut buffer, Config { max_len: 2, ..Config::DEFAULT });
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), writer.write(&"abc"));
+
+    let mut writer = BytesWriter::with_config(&mut buffer, Config { max_len: 3, ..Config::DEFAULT });
+    writer.write(&"abc").unwrap();
+
+    assert_eq!(data, buffer);
+}
@@ -1,4 +1,8 @@
-use minbin::{read_bytes, write_bytes, ToFromBytes};
+use std::ffi::CStr;
+use std::ops::{Bound, Range, RangeInclusive};
+use std::time::Duration;
+
+use minbin::{read_bytes, write_bytes, ToFromByteError, ToFromBytes};
 
 #[test]
 fn test_option() {
@@ -12,6 +16,21 @@ fn test_option() {
     }
 }
 
+#[test]
+fn test_result() {
+    for expected in [Ok(42u32), Ok(u32::MAX), Err(-1i8), Err(i8::MIN)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Result<u32, i8>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(5, <Result<u32, i8>>::MAX_BYTES);
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<Result<u8, u8>>(&[2, 0]).map(|(value, _)| value));
+}
+
 #[test]
 fn test_str() {
     for expected in ["", "something", "else"] {
@@ -27,3 +46,111 @@ fn test_str() {
         assert_eq!(expected, actual);
     }
 }
+
+#[test]
+fn test_cstr() {
+    for expected in [c"", c"something", c"else"] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+
+        assert_eq!(expected.to_bytes_with_nul(), &buffer[..write_pos]);
+
+        let (actual, read_pos): (&CStr, usize) = read_bytes(&buffer).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    // Only consumes up to and including the terminator
+    assert_eq!(Ok((c"ab", 3)), read_bytes::<&CStr>(b"ab\0cd"));
+
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), read_bytes::<&CStr>(b"").map(|(value, _)| value));
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), read_bytes::<&CStr>(b"abc").map(|(value, _)| value));
+}
+
+#[test]
+fn test_array() {
+    for expected in [[0u16; 4], [1, 2, 3, 4], [u16::MAX; 4]] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): ([u16; 4], usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(8, read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(8, <[u16; 4]>::MAX_BYTES);
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), read_bytes::<[u16; 4]>(&[0; 7]).map(|(value, _)| value));
+}
+
+#[test]
+fn test_array_empty() {
+    let expected: [u64; 0] = [];
+
+    let mut buffer = [0u8; 0];
+    let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+    let (actual, read_pos): ([u64; 0], usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+    assert_eq!(0, read_pos);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_byte_slice() {
+    for expected in [&b""[..], b"\x00\x01\x02", b"something"] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+
+        assert_eq!(expected.byte_count(), write_pos);
+
+        let (actual, read_pos): (&[u8], usize) = read_bytes(&buffer).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_duration() {
+    for expected in [Duration::ZERO, Duration::new(1, 999_999_999), Duration::MAX] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Duration, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    let mut invalid = [0u8; 12];
+    invalid[8..].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<Duration>(&invalid).map(|(value, _)| value));
+}
+
+#[test]
+fn test_ranges() {
+    let expected = (10u32..20, -5i16..=5);
+
+    let mut buffer = vec![0u8; expected.byte_count()];
+    let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+    let (actual, read_pos): ((Range<u32>, RangeInclusive<i16>), usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+    assert_eq!(12, read_pos);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_bound() {
+    for expected in [Bound::Included(1u64), Bound::Excluded(u64::MAX), Bound::Unbounded] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Bound<u64>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<Bound<u8>>(&[3, 0]).map(|(value, _)| value));
+}
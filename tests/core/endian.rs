@@ -0,0 +1,120 @@
+use std::num::{NonZeroI32, NonZeroU16};
+
+use minbin::{read_bytes, write_bytes, Be, Le, ToFromByteError, ToFromBytes, I24, I48, U24};
+
+#[test]
+fn test_le_u32() {
+    for expected in [Le(u32::MIN), Le(0x0102_0304), Le(u32::MAX)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Le<u32>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+        assert_eq!(expected.0.to_le_bytes(), buffer.as_slice());
+    }
+}
+
+#[test]
+fn test_le_i16() {
+    for expected in [Le(i16::MIN), Le(-2), Le(i16::MAX)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Le<i16>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+        assert_eq!(expected.0.to_le_bytes(), buffer.as_slice());
+    }
+}
+
+#[test]
+fn test_le_usize() {
+    let expected = Le(0x0102usize);
+
+    let mut buffer = vec![0u8; expected.byte_count()];
+    write_bytes(&expected, &mut buffer).unwrap();
+
+    assert_eq!([2, 1, 0, 0, 0, 0, 0, 0], buffer.as_slice());
+    assert_eq!(expected, read_bytes(&buffer).unwrap().0);
+}
+
+#[test]
+fn test_le_f64() {
+    let expected = Le(-1.5f64);
+
+    let mut buffer = vec![0u8; expected.byte_count()];
+    write_bytes(&expected, &mut buffer).unwrap();
+
+    assert_eq!(expected.0.to_le_bytes(), buffer.as_slice());
+    assert_eq!(expected, read_bytes(&buffer).unwrap().0);
+}
+
+#[test]
+fn test_le_odd_ints() {
+    let mut buffer = [0u8; 3];
+    write_bytes(&Le(U24(0x01_0203)), &mut buffer).unwrap();
+
+    assert_eq!([3, 2, 1], buffer);
+    assert_eq!(Le(U24(0x01_0203)), read_bytes(&buffer).unwrap().0);
+
+    for expected in [Le(I24(I24::MIN)), Le(I24(-1)), Le(I24(I24::MAX))] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        write_bytes(&expected, &mut buffer).unwrap();
+
+        assert_eq!(&(expected.0).0.to_le_bytes()[..3], buffer.as_slice());
+        assert_eq!(expected, read_bytes(&buffer).unwrap().0);
+    }
+
+    let mut buffer = [0u8; 6];
+    write_bytes(&Le(I48(-2)), &mut buffer).unwrap();
+
+    assert_eq!([0xfe, 0xff, 0xff, 0xff, 0xff, 0xff], buffer);
+    assert_eq!(Le(I48(-2)), read_bytes(&buffer).unwrap().0);
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&Le(U24(U24::MAX + 1)), &mut [0u8; 3]));
+    assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&Le(I24(I24::MIN - 1)), &mut [0u8; 3]));
+}
+
+#[test]
+fn test_le_nonzero() {
+    let expected = Le(NonZeroU16::new(0x0102).unwrap());
+
+    let mut buffer = [0u8; 2];
+    write_bytes(&expected, &mut buffer).unwrap();
+
+    assert_eq!([2, 1], buffer);
+    assert_eq!(expected, read_bytes(&buffer).unwrap().0);
+
+    let expected = Be(NonZeroI32::new(-1).unwrap());
+
+    let mut buffer = [0u8; 4];
+    write_bytes(&expected, &mut buffer).unwrap();
+
+    assert_eq!(expected, read_bytes(&buffer).unwrap().0);
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<Le<NonZeroU16>>(&[0, 0]).map(|(value, _)| value));
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<Be<NonZeroI32>>(&[0; 4]).map(|(value, _)| value));
+}
+
+#[test]
+fn test_be_matches_default() {
+    let mut be_buffer = [0u8; 8];
+    let mut default_buffer = [0u8; 8];
+
+    write_bytes(&Be(0x0102_0304_0506_0708u64), &mut be_buffer).unwrap();
+    write_bytes(&0x0102_0304_0506_0708u64, &mut default_buffer).unwrap();
+
+    assert_eq!(default_buffer, be_buffer);
+    assert_eq!(Be(0x0102_0304_0506_0708u64), read_bytes(&be_buffer).unwrap().0);
+}
+
+#[test]
+fn test_mixed_byte_order() {
+    let expected = (Le(1u16), Be(1u16), 1u16);
+
+    let mut buffer = vec![0u8; expected.byte_count()];
+    write_bytes(&expected, &mut buffer).unwrap();
+
+    assert_eq!([1, 0, 0, 1, 0, 1], buffer.as_slice());
+}
@@ -0,0 +1,58 @@
+use minbin::{read_bytes, write_bytes, Decimal, Fixed, ToFromByteError, ToFromBytes};
+
+#[test]
+fn test_fixed() {
+    for expected in [Decimal::<2>::MIN, Decimal::from_int(-3).unwrap(), Decimal::from_mantissa(1234).unwrap(), Decimal::<2>::MAX] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Decimal<2>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(8, read_pos);
+        assert_eq!(expected, actual);
+        assert_eq!(expected.mantissa().to_be_bytes(), buffer.as_slice());
+    }
+}
+
+#[test]
+fn test_fixed_rejects_min() {
+    assert_eq!(None, Fixed::<i32, 3>::from_mantissa(i32::MIN));
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<Fixed<i32, 3>>(&i32::MIN.to_be_bytes()).map(|(value, _)| value));
+}
+
+#[test]
+fn test_fixed_int_conversions() {
+    let value = Fixed::<i32, 2>::from_int(42).unwrap();
+
+    assert_eq!(4200, value.mantissa());
+    assert_eq!(42, value.to_int());
+    assert_eq!(-1, Fixed::<i32, 2>::from_mantissa(-199).unwrap().to_int());
+    assert_eq!(None, Fixed::<i32, 2>::from_int(i32::MAX / 10));
+}
+
+#[test]
+fn test_fixed_float_conversions() {
+    assert_eq!(1235, Decimal::<2>::from_f64(12.345).unwrap().mantissa());
+    assert_eq!(-1235, Decimal::<2>::from_f64(-12.345).unwrap().mantissa());
+    assert_eq!(0, Decimal::<2>::from_f64(0.004).unwrap().mantissa());
+    assert_eq!(0, Decimal::<0>::from_f64(0.49999999999999994).unwrap().mantissa());
+    assert_eq!(0, Decimal::<0>::from_f64(-0.49999999999999994).unwrap().mantissa());
+    assert_eq!(3, Decimal::<0>::from_f64(2.5).unwrap().mantissa());
+    assert_eq!(-3, Decimal::<0>::from_f64(-2.5).unwrap().mantissa());
+    assert_eq!(12.34, Decimal::<2>::from_mantissa(1234).unwrap().to_f64());
+
+    assert_eq!(None, Decimal::<2>::from_f64(f64::NAN));
+    assert_eq!(None, Decimal::<2>::from_f64(f64::INFINITY));
+    assert_eq!(None, Decimal::<2>::from_f64(1e17));
+    assert_eq!(None, Fixed::<i16, 1>::from_f64(3276.8));
+    assert_eq!(Some(32767), Fixed::<i16, 1>::from_f64(3276.7).map(|value| value.mantissa()));
+    assert_eq!(None, Fixed::<i16, 1>::from_f64(3276.75));
+    assert_eq!(None, Fixed::<i16, 1>::from_f64(-3276.75));
+}
+
+#[test]
+fn test_fixed_display() {
+    assert_eq!("12.34", Decimal::<2>::from_mantissa(1234).unwrap().to_string());
+    assert_eq!("-0.05", Decimal::<2>::from_mantissa(-5).unwrap().to_string());
+    assert_eq!("7", Fixed::<i16, 0>::from_mantissa(7).unwrap().to_string());
+    assert_eq!("0.000", Fixed::<i128, 3>::default().to_string());
+}
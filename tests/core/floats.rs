@@ -0,0 +1,43 @@
+use minbin::{read_bytes, write_bytes, Canonical, Finite, ToFromByteError, ToFromBytes};
+
+#[test]
+fn test_canonical_collapses() {
+    for (value, expected) in [(f32::from_bits(0x7fc0_0001), f32::NAN), (-f32::NAN, f32::NAN), (-0.0, 0.0), (1.5, 1.5)] {
+        let mut buffer = vec![0u8; Canonical(value).byte_count()];
+        write_bytes(&Canonical(value), &mut buffer).unwrap();
+
+        assert_eq!(expected.to_bits().to_be_bytes(), buffer.as_slice());
+
+        let (actual, read_pos): (Canonical<f32>, usize) = read_bytes(&buffer).unwrap();
+
+        assert_eq!(4, read_pos);
+        assert_eq!(expected.to_bits(), actual.0.to_bits());
+    }
+}
+
+#[test]
+fn test_canonical_rejects() {
+    for bits in [0x7ff8_0000_0000_0001u64, (-0.0f64).to_bits(), (-f64::NAN).to_bits()] {
+        let result = read_bytes::<Canonical<f64>>(&bits.to_be_bytes());
+
+        assert_eq!(Err(ToFromByteError::InvalidValue), result.map(|(value, _)| value));
+    }
+}
+
+#[test]
+fn test_finite() {
+    for expected in [f64::MIN, 0.0, 42.5, f64::MAX] {
+        let mut buffer = vec![0u8; Finite(expected).byte_count()];
+        write_bytes(&Finite(expected), &mut buffer).unwrap();
+        let (actual, _): (Finite<f64>, usize) = read_bytes(&buffer).unwrap();
+
+        assert_eq!(Finite(expected), actual);
+    }
+
+    for invalid in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut buffer = vec![0u8; 8];
+
+        assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&Finite(invalid), &mut buffer));
+        assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<Finite<f64>>(&invalid.to_bits().to_be_bytes()).map(|(value, _)| value));
+    }
+}
@@ -1,6 +1,16 @@
+mod bits;
+mod bounded;
+mod config;
 mod containers;
+mod endian;
+mod fixed;
+mod floats;
+mod net;
+mod odd_ints;
+mod prefixed;
 mod primitives;
 mod reader;
 mod structs;
 mod tuples;
+mod varint;
 mod writer;
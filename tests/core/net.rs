@@ -0,0 +1,46 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use minbin::{read_bytes, write_bytes, ToFromByteError, ToFromBytes};
+
+#[test]
+fn test_ip_addr() {
+    for expected in [IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)), IpAddr::V6(Ipv6Addr::LOCALHOST)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (IpAddr, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_ip_addr_layout() {
+    let expected = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 42));
+
+    let mut buffer = vec![0u8; expected.byte_count()];
+    write_bytes(&expected, &mut buffer).unwrap();
+
+    assert_eq!([4, 10, 0, 0, 42], buffer.as_slice());
+}
+
+#[test]
+fn test_socket_addr() {
+    let v4 = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080));
+    let v6 = SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, u16::MAX, 1, 2));
+
+    for expected in [v4, v6] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (SocketAddr, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_unknown_family() {
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<IpAddr>(&[5, 0, 0, 0, 0]).map(|(value, _)| value));
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<SocketAddr>(&[0, 0, 0, 0, 0, 0, 0]).map(|(value, _)| value));
+}
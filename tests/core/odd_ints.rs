@@ -0,0 +1,70 @@
+use minbin::{read_bytes, write_bytes, ToFromByteError, ToFromBytes, I24, I48, U24, U48};
+
+#[test]
+fn test_u24() {
+    for expected in [U24(U24::MIN), U24(42), U24(U24::MAX)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (U24, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(3, read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    let mut buffer = [0u8; 3];
+    write_bytes(&U24(0x01_02_03), &mut buffer).unwrap();
+    assert_eq!([1, 2, 3], buffer);
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&U24(U24::MAX + 1), &mut buffer));
+}
+
+#[test]
+fn test_i24() {
+    for expected in [I24(I24::MIN), I24(-1), I24(0), I24(42), I24(I24::MAX)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (I24, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(3, read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(I24(-1), read_bytes::<I24>(&[0xff, 0xff, 0xff]).unwrap().0);
+    assert_eq!(I24(-8_388_608), read_bytes::<I24>(&[0x80, 0, 0]).unwrap().0);
+
+    let mut buffer = [0u8; 3];
+    assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&I24(I24::MAX + 1), &mut buffer));
+    assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&I24(I24::MIN - 1), &mut buffer));
+}
+
+#[test]
+fn test_u48() {
+    for expected in [U48(U48::MIN), U48(42), U48(U48::MAX)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (U48, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(6, read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    let mut buffer = [0u8; 6];
+    assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&U48(u64::MAX), &mut buffer));
+}
+
+#[test]
+fn test_i48() {
+    for expected in [I48(I48::MIN), I48(-1), I48(42), I48(I48::MAX)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (I48, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(6, read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(I48(-2), read_bytes::<I48>(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]).unwrap().0);
+
+    let mut buffer = [0u8; 6];
+    assert_eq!(Err(ToFromByteError::InvalidValue), write_bytes(&I48(i64::MIN), &mut buffer));
+}
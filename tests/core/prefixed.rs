@@ -0,0 +1,57 @@
+use minbin::{read_bytes, write_bytes, Prefixed, ToFromByteError, ToFromBytes, VarU32};
+
+#[test]
+fn test_prefixed_str() {
+    for expected in ["", "something", "else"] {
+        let expected: Prefixed<u8, &str> = Prefixed::new(expected);
+
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+
+        assert_eq!(1 + expected.len(), write_pos);
+
+        let (actual, read_pos): (Prefixed<u8, &str>, usize) = read_bytes(&buffer).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_prefixed_widths() {
+    let mut buffer = [0u8; 5];
+
+    write_bytes(&Prefixed::<u16, &[u8]>::new(b"abc"), &mut buffer).unwrap();
+    assert_eq!(b"\x00\x03abc", &buffer);
+
+    let mut buffer = [0u8; 7];
+
+    write_bytes(&Prefixed::<u32, &[u8]>::new(b"abc"), &mut buffer).unwrap();
+    assert_eq!(b"\x00\x00\x00\x03abc", &buffer);
+}
+
+#[test]
+fn test_prefixed_varint() {
+    let bytes = [7u8; 200];
+    let expected: Prefixed<VarU32, &[u8]> = Prefixed::new(&bytes);
+
+    let mut buffer = vec![0u8; expected.byte_count()];
+    write_bytes(&expected, &mut buffer).unwrap();
+
+    assert_eq!(202, buffer.len());
+    assert_eq!([0xc8, 0x01], buffer[..2]);
+
+    let (actual, _): (Prefixed<VarU32, &[u8]>, usize) = read_bytes(&buffer).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_prefixed_too_long() {
+    let bytes = [0u8; 256];
+    let value: Prefixed<u8, &[u8]> = Prefixed::new(&bytes);
+
+    let mut buffer = vec![0u8; 512];
+
+    assert_eq!(Err(ToFromByteError::MaxBytesExceeded), write_bytes(&value, &mut buffer));
+}
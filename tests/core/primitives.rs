@@ -1,4 +1,36 @@
-use minbin::{read_bytes, write_bytes, ToFromBytes};
+use std::num::{NonZeroI64, NonZeroU32};
+
+use minbin::{read_bytes, write_bytes, ToFromByteError, ToFromBytes};
+
+#[test]
+fn test_bool() {
+    for expected in [false, true] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (bool, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<bool>(&[2]).map(|(value, _)| value));
+}
+
+#[test]
+fn test_char() {
+    for expected in ['\0', 'a', '\u{D7FF}', '\u{E000}', '🦀', char::MAX] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (char, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    for invalid in [0xD800u32, 0xDFFF, 0x11_0000, u32::MAX] {
+        assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<char>(&invalid.to_be_bytes()).map(|(value, _)| value));
+    }
+}
 
 #[test]
 fn test_u8() {
@@ -119,3 +151,113 @@ fn test_i128() {
         assert_eq!(expected, actual);
     }
 }
+
+#[test]
+fn test_f32() {
+    for expected in [f32::MIN, -0.0, 0.0, 42.5, f32::MAX, f32::INFINITY, f32::NEG_INFINITY] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (f32, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected.to_bits(), actual.to_bits());
+    }
+}
+
+#[test]
+fn test_f64() {
+    for expected in [f64::MIN, -0.0, 0.0, 42.5, f64::MAX, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (f64, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected.to_bits(), actual.to_bits());
+    }
+}
+
+#[test]
+fn test_float_nan_payload() {
+    let expected = f32::from_bits(0x7fc0_0001);
+
+    let mut buffer = vec![0u8; expected.byte_count()];
+    write_bytes(&expected, &mut buffer).unwrap();
+    let (actual, _): (f32, usize) = read_bytes(&buffer).unwrap();
+
+    assert_eq!(expected.to_bits(), actual.to_bits());
+}
+
+#[test]
+fn test_nonzero_u32() {
+    for expected in [NonZeroU32::MIN, NonZeroU32::new(42).unwrap(), NonZeroU32::MAX] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (NonZeroU32, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+        assert_eq!(expected.get().to_be_bytes(), buffer.as_slice());
+    }
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<NonZeroU32>(&[0; 4]).map(|(value, _)| value));
+}
+
+#[test]
+fn test_nonzero_i64() {
+    for expected in [NonZeroI64::MIN, NonZeroI64::new(-1).unwrap(), NonZeroI64::MAX] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (NonZeroI64, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<NonZeroI64>(&[0; 8]).map(|(value, _)| value));
+}
+
+#[test]
+fn test_option_nonzero() {
+    for expected in [None, NonZeroU32::new(7)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (Option<NonZeroU32>, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(expected.byte_count(), read_pos);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_usize() {
+    for expected in [usize::MIN, 42, usize::MAX] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (usize, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(8, read_pos);
+        assert_eq!(expected, actual);
+        assert_eq!((expected as u64).to_be_bytes(), buffer.as_slice());
+    }
+}
+
+#[test]
+fn test_isize() {
+    for expected in [isize::MIN, -42, isize::MAX] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (isize, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(8, read_pos);
+        assert_eq!(expected, actual);
+        assert_eq!((expected as i64).to_be_bytes(), buffer.as_slice());
+    }
+}
+
+#[test]
+#[cfg(target_pointer_width = "32")]
+fn test_usize_overflow() {
+    let too_large = (u32::MAX as u64 + 1).to_be_bytes();
+
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<usize>(&too_large).map(|(value, _)| value));
+}
@@ -1,4 +1,4 @@
-use minbin::{BytesReader, ToFromByteError};
+use minbin::{BytesReader, BytesWriter, Config, ToFromByteError};
 
 #[test]
 fn reader_partial() {
@@ -38,3 +38,141 @@ fn reader_chunks() {
     assert_eq!(reader.read_bytes(3).unwrap(), b"bin");
     assert_eq!(reader.pos, 3 + 3);
 }
+
+#[test]
+fn reader_read_len() {
+    let data = [0, 0, 0, 3, 1, 2, 3];
+    let mut reader = BytesReader::new(&data);
+
+    assert_eq!(reader.read_len::<&[u8]>().unwrap(), 3);
+    assert_eq!(reader.pos, 4);
+    assert_eq!(reader.capacity_for::<u8>(3), 3);
+    assert_eq!(reader.capacity_for::<u8>(u32::MAX as usize), 3);
+    assert_eq!(reader.capacity_for::<u32>(3), 0);
+}
+
+#[test]
+fn reader_read_len_exceeds_max() {
+    let data = u32::MAX.to_be_bytes();
+    let mut reader = BytesReader::new(&data);
+
+    let err = reader.read_len::<&str>().unwrap_err();
+    assert_eq!(err, ToFromByteError::MaxBytesExceeded);
+}
+
+#[test]
+fn reader_skip_padding() {
+    let data = [1, 0, 0, 0, 2, 0xff, 0, 0, 3];
+    let mut reader = BytesReader::new(&data);
+
+    assert_eq!(reader.read::<u8>().unwrap(), 1);
+    reader.align_to(4).unwrap();
+    assert_eq!(reader.read::<u8>().unwrap(), 2);
+
+    // Non-zero padding is ignored unless strict
+    reader.align_to(4).unwrap();
+    assert_eq!(reader.read::<u8>().unwrap(), 3);
+
+    let err = reader.skip_padding(1).unwrap_err();
+    assert_eq!(err, ToFromByteError::NotEnoughBytes);
+}
+
+#[test]
+fn reader_skip_padding_strict() {
+    let data = [0, 0xff, 0, 0, 3];
+    let mut reader = BytesReader::with_config(&data, Config { strict: true, ..Config::DEFAULT });
+
+    reader.skip_padding(1).unwrap();
+
+    let err = reader.align_to(4).unwrap_err();
+    assert_eq!(err, ToFromByteError::InvalidValue);
+
+    let mut reader = BytesReader::with_config(&data[2..], Config { strict: true, ..Config::DEFAULT });
+
+    reader.skip_padding(2).unwrap();
+    assert_eq!(reader.read::<u8>().unwrap(), 3);
+}
+
+#[test]
+fn reader_peek_and_skip() {
+    let data = [0, 2, 7, 7, 9];
+    let mut reader = BytesReader::new(&data);
+
+    assert_eq!(reader.remaining(), 5);
+    assert_eq!(reader.peek::<u16>().unwrap(), 2);
+    assert_eq!(reader.pos, 0);
+
+    reader.skip(4).unwrap();
+    assert_eq!(reader.remaining(), 1);
+    assert!(!reader.is_empty());
+
+    // A failed peek leaves the cursor where it was
+    assert_eq!(reader.peek::<u16>().unwrap_err(), ToFromByteError::NotEnoughBytes);
+    assert_eq!(reader.pos, 4);
+
+    assert_eq!(reader.skip(2).unwrap_err(), ToFromByteError::NotEnoughBytes);
+    assert_eq!(reader.read::<u8>().unwrap(), 9);
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn reader_sub_reader() {
+    let data = [0, 0, 0, 4, 0, 0, 0, 3, 0xff, 0xff];
+    let mut reader = BytesReader::with_config(&data, Config { max_len: 8, ..Config::DEFAULT });
+
+    let len = reader.read_len::<&[u8]>().unwrap();
+    let mut sub = reader.sub_reader(len).unwrap();
+
+    assert_eq!(sub.config.max_len, 8);
+    assert_eq!(sub.read::<u32>().unwrap(), 3);
+    // Confined to the sub-message even though the parent has more bytes
+    assert_eq!(sub.read::<u8>().unwrap_err(), ToFromByteError::NotEnoughBytes);
+
+    sub.finish().unwrap();
+
+    assert_eq!(reader.pos, 8);
+    assert_eq!(reader.read::<u16>().unwrap(), 0xffff);
+}
+
+#[test]
+fn reader_sub_reader_trailing_bytes() {
+    let data = [1, 2, 3];
+    let mut reader = BytesReader::new(&data);
+
+    let mut sub = reader.sub_reader(2).unwrap();
+    assert_eq!(sub.read::<u8>().unwrap(), 1);
+    assert_eq!(sub.finish().unwrap_err(), ToFromByteError::TrailingBytes);
+    assert_eq!(reader.pos, 0);
+
+    let mut sub = reader.sub_reader(2).unwrap();
+    sub.skip(2).unwrap();
+    sub.finish().unwrap();
+    assert_eq!(reader.pos, 2);
+
+    assert_eq!(reader.sub_reader(2).err(), Some(ToFromByteError::NotEnoughBytes));
+}
+
+#[test]
+fn reader_sub_reader_align() {
+    let mut buffer = [0u8; 8];
+    let mut writer = BytesWriter::new(&mut buffer);
+
+    writer.write(&7u8).unwrap();
+    writer.write(&1u8).unwrap();
+    writer.align_to(4).unwrap();
+    writer.write(&0xdead_beefu32).unwrap();
+
+    let mut reader = BytesReader::with_config(&buffer, Config { strict: true, ..Config::DEFAULT });
+
+    let len = reader.read::<u8>().unwrap();
+    let mut sub = reader.sub_reader(len as usize).unwrap();
+
+    // Alignment and positions stay relative to the start of the buffer
+    assert_eq!(sub.read::<u8>().unwrap(), 1);
+    sub.align_to(4).unwrap();
+    assert_eq!(sub.pos, 4);
+    assert_eq!(sub.read::<u32>().unwrap(), 0xdead_beef);
+
+    sub.finish().unwrap();
+    assert_eq!(reader.pos, 8);
+}
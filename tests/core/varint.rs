@@ -0,0 +1,80 @@
+use minbin::{read_bytes, write_bytes, ToFromByteError, ToFromBytes, VarI32, VarI64, VarU128, VarU16, VarU32, VarU64};
+
+#[test]
+fn test_var_u64() {
+    for (expected, byte_count) in [(0u64, 1), (127, 1), (128, 2), (16_383, 2), (16_384, 3), (u64::MAX, 10)] {
+        let expected = VarU64(expected);
+
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (VarU64, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(byte_count, expected.byte_count());
+        assert_eq!(byte_count, read_pos);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_var_u_layout() {
+    let mut buffer = [0u8; 2];
+    write_bytes(&VarU32(300), &mut buffer).unwrap();
+
+    assert_eq!([0xac, 0x02], buffer);
+}
+
+#[test]
+fn test_var_u_bounds() {
+    for expected in [VarU16(u16::MAX), VarU16(0)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        write_bytes(&expected, &mut buffer).unwrap();
+
+        assert_eq!(expected, read_bytes(&buffer).unwrap().0);
+    }
+
+    for expected in [VarU128(u128::MAX), VarU128(1 << 127)] {
+        let mut buffer = vec![0u8; expected.byte_count()];
+        write_bytes(&expected, &mut buffer).unwrap();
+
+        assert_eq!(19, buffer.len());
+        assert_eq!(expected, read_bytes(&buffer).unwrap().0);
+    }
+}
+
+#[test]
+fn test_var_i() {
+    for (expected, byte_count) in [(0i64, 1), (-1, 1), (63, 1), (-64, 1), (64, 2), (i64::MIN, 10), (i64::MAX, 10)] {
+        let expected = VarI64(expected);
+
+        let mut buffer = vec![0u8; expected.byte_count()];
+        let write_pos = write_bytes(&expected, &mut buffer).unwrap();
+        let (actual, read_pos): (VarI64, usize) = read_bytes(&buffer[..write_pos]).unwrap();
+
+        assert_eq!(byte_count, read_pos);
+        assert_eq!(expected, actual);
+    }
+
+    let mut buffer = [0u8; 1];
+    write_bytes(&VarI32(-1), &mut buffer).unwrap();
+
+    assert_eq!([1], buffer);
+}
+
+#[test]
+fn test_var_rejects_overlong() {
+    // 0 and 1 with a redundant zero group
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<VarU32>(&[0x80, 0x00]).map(|(value, _)| value));
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<VarU32>(&[0x81, 0x80, 0x00]).map(|(value, _)| value));
+}
+
+#[test]
+fn test_var_rejects_out_of_range() {
+    // u16::MAX + 1 needs 17 bits
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<VarU16>(&[0x80, 0x80, 0x04]).map(|(value, _)| value));
+
+    // More groups than a u32 can ever need
+    assert_eq!(Err(ToFromByteError::InvalidValue), read_bytes::<VarU32>(&[0xff, 0xff, 0xff, 0xff, 0xff, 0x01]).map(|(value, _)| value));
+
+    // Continuation bit on the last available byte
+    assert_eq!(Err(ToFromByteError::NotEnoughBytes), read_bytes::<VarU32>(&[0x80]).map(|(value, _)| value));
+}
@@ -1,4 +1,4 @@
-use minbin::{BytesWriter, ToFromByteError};
+use minbin::{padding_for, BytesWriter, ToFromByteError};
 
 #[test]
 fn writer_partial() {
@@ -30,3 +30,57 @@ fn writer_overflow() {
     let err = writer.write_bytes(&[5]).unwrap_err();
     assert_eq!(err, ToFromByteError::NotEnoughBytes);
 }
+
+#[test]
+fn writer_pad_and_align() {
+    let mut buffer = [0xffu8; 16];
+    let mut writer = BytesWriter::new(&mut buffer);
+
+    writer.write_bytes(&[1]).unwrap();
+    writer.align_to(4).unwrap();
+    assert_eq!(writer.pos, 4);
+
+    // Already aligned, nothing to write
+    writer.align_to(4).unwrap();
+    assert_eq!(writer.pos, 4);
+
+    writer.pad(2).unwrap();
+    writer.align_to(8).unwrap();
+    assert_eq!(writer.pos, 8);
+
+    let err = writer.pad(9).unwrap_err();
+    assert_eq!(err, ToFromByteError::NotEnoughBytes);
+
+    let err = writer.pad(usize::MAX).unwrap_err();
+    assert_eq!(err, ToFromByteError::NotEnoughBytes);
+
+    assert_eq!(&buffer[..9], &[1, 0, 0, 0, 0, 0, 0, 0, 0xff]);
+}
+
+#[test]
+fn padding_for_alignments() {
+    assert_eq!(padding_for(0, 8), 0);
+    assert_eq!(padding_for(1, 8), 7);
+    assert_eq!(padding_for(8, 8), 0);
+    assert_eq!(padding_for(5, 3), 1);
+    assert_eq!(padding_for(5, 1), 0);
+    assert_eq!(padding_for(5, 0), 0);
+}
+
+#[test]
+fn writer_write_len() {
+    let mut buffer = [0u8; 8];
+    let mut writer = BytesWriter::new(&mut buffer);
+
+    writer.write_len::<&[u8]>(3).unwrap();
+    writer.write_len_with::<&[u8], u16>(3).unwrap();
+    assert_eq!(writer.pos, 6);
+
+    let err = writer.write_len::<&str>(102_401).unwrap_err();
+    assert_eq!(err, ToFromByteError::MaxBytesExceeded);
+
+    let err = writer.write_len_with::<&[u8], u8>(256).unwrap_err();
+    assert_eq!(err, ToFromByteError::MaxBytesExceeded);
+
+    assert_eq!(&buffer[..6], &[0, 0, 0, 3, 0, 3]);
+}
@@ -0,0 +1,62 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::BuildHasherDefault;
+use std::hash::DefaultHasher;
+
+use minbin::{from_bytes, to_bytes, ToFromByteError};
+
+#[test]
+fn test_hash_map() {
+    let expected: HashMap<String, u32> = (0..100).map(|i| (i.to_string(), i)).collect();
+
+    let bytes = to_bytes(&expected).unwrap();
+    let actual: HashMap<String, u32> = from_bytes(&bytes).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_hash_map_deterministic() {
+    let entries: Vec<(u16, u8)> = (0..100).map(|i| (i * 7, i as u8)).collect();
+
+    let random: HashMap<u16, u8> = entries.iter().copied().collect();
+    let seeded: HashMap<u16, u8, BuildHasherDefault<DefaultHasher>> = entries.iter().rev().copied().collect();
+
+    // Big-endian integers sort the same as their values, so this matches the BTreeMap layout
+    let ordered: BTreeMap<u16, u8> = entries.into_iter().collect();
+
+    assert_eq!(to_bytes(&ordered).unwrap(), to_bytes(&random).unwrap());
+    assert_eq!(to_bytes(&ordered).unwrap(), to_bytes(&seeded).unwrap());
+}
+
+#[test]
+fn test_hash_map_rejects_duplicates() {
+    let duplicate = to_bytes(&vec![(1u8, 0u8), (1, 1)]).unwrap();
+    assert_eq!(Err(ToFromByteError::InvalidValue), from_bytes::<HashMap<u8, u8>>(&duplicate));
+
+    let unordered = to_bytes(&vec![(2u8, 0u8), (1, 1)]).unwrap();
+    assert_eq!(HashMap::from([(1, 1), (2, 0)]), from_bytes::<HashMap<u8, u8>>(&unordered).unwrap());
+}
+
+#[test]
+fn test_hash_set() {
+    let expected: HashSet<String> = ["c", "a", "bb"].into_iter().map(String::from).collect();
+
+    let bytes = to_bytes(&expected).unwrap();
+
+    // Sorted by encoded bytes, so the length prefix of each string comes first
+    assert_eq!(to_bytes(&vec!["a".to_string(), "c".to_string(), "bb".to_string()]).unwrap(), bytes);
+    assert_eq!(expected, from_bytes::<HashSet<String>>(&bytes).unwrap());
+
+    let duplicate = to_bytes(&vec![1i32, 1]).unwrap();
+    assert_eq!(Err(ToFromByteError::InvalidValue), from_bytes::<HashSet<i32>>(&duplicate));
+}
+
+#[test]
+fn test_glob_import_keeps_std_paths() {
+    use minbin::*;
+    use std::collections::HashSet;
+
+    let expected: HashSet<u8> = [1, 2, 3].into_iter().collect();
+
+    assert_eq!(expected, from_bytes::<HashSet<u8>>(&to_bytes(&expected).unwrap()).unwrap());
+}
@@ -1,2 +1,4 @@
 mod alloc;
 mod core;
+#[cfg(feature = "std")]
+mod hashed;